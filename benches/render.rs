@@ -0,0 +1,66 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use freeciv_rs::{
+    tiles,
+    world::{generate, Parameters, Renderer},
+};
+use image::{imageops, RgbaImage};
+
+/// Tiles per side of the image the sprite benchmarks draw on.
+const SPRITE_TILES: u32 = 32;
+
+fn render(c: &mut Criterion) {
+    let renderer = Renderer::default();
+    let mut group = c.benchmark_group("render");
+    group.sample_size(10);
+
+    for (width, height) in [(80, 50), (160, 100)] {
+        let world = generate(Parameters {
+            width,
+            height,
+            seed: 7,
+            ..Parameters::default()
+        })
+        .unwrap();
+
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("{width}x{height}")),
+            &world,
+            |b, world| b.iter(|| renderer.render(world)),
+        );
+    }
+
+    group.finish();
+}
+
+/// Compares blending an opaque sprite with `imageops::overlay` to drawing it
+/// like the renderer does, once for every tile of an image.
+fn draw_sprite(c: &mut Criterion) {
+    let sprite = image::load_from_memory(include_bytes!("../assets/imgs/grassland.png"))
+        .unwrap()
+        .to_rgba8();
+    let (width, height) = (sprite.width(), sprite.height());
+    let mut base = RgbaImage::new(width * SPRITE_TILES, height * SPRITE_TILES);
+    let positions: Vec<(u32, u32)> = (0..SPRITE_TILES)
+        .flat_map(|y| (0..SPRITE_TILES).map(move |x| (x * width, y * height)))
+        .collect();
+
+    let mut group = c.benchmark_group("draw_sprite");
+    group.bench_function("overlay", |b| {
+        b.iter(|| {
+            for &(x, y) in &positions {
+                imageops::overlay(&mut base, &sprite, i64::from(x), i64::from(y));
+            }
+        });
+    });
+    group.bench_function("draw", |b| {
+        b.iter(|| {
+            for &(x, y) in &positions {
+                tiles::draw_sprite("grassland", &mut base, x, y);
+            }
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, render, draw_sprite);
+criterion_main!(benches);
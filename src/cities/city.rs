@@ -0,0 +1,52 @@
+use crate::game::PlayerId;
+
+/// Unique identifier of a [`City`] within a game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CityId(pub(crate) u32);
+
+/// A city founded by a player.
+///
+/// TODO: Citizens, production and growth.
+#[derive(Debug, Clone)]
+pub struct City {
+    pub(crate) id: CityId,
+    pub(crate) owner: PlayerId,
+    pub(crate) x: usize,
+    pub(crate) y: usize,
+    pub(crate) size: u8,
+}
+
+impl City {
+    /// Creates a new city of size 1.
+    #[must_use]
+    pub const fn new(id: CityId, owner: PlayerId, x: usize, y: usize) -> Self {
+        Self {
+            id,
+            owner,
+            x,
+            y,
+            size: 1,
+        }
+    }
+
+    #[must_use]
+    pub const fn id(&self) -> CityId {
+        self.id
+    }
+
+    #[must_use]
+    pub const fn owner(&self) -> PlayerId {
+        self.owner
+    }
+
+    /// The coordinates of the city center.
+    #[must_use]
+    pub const fn position(&self) -> (usize, usize) {
+        (self.x, self.y)
+    }
+
+    #[must_use]
+    pub const fn size(&self) -> u8 {
+        self.size
+    }
+}
@@ -0,0 +1,3 @@
+mod city;
+
+pub use city::{City, CityId};
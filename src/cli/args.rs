@@ -0,0 +1,41 @@
+use std::{str::FromStr, vec::IntoIter};
+
+use super::CliError;
+
+/// The command line arguments of a subcommand, consumed one by one.
+pub(crate) struct Args {
+    args: IntoIter<String>,
+}
+
+impl Args {
+    pub(crate) fn new<I: IntoIterator<Item = String>>(args: I) -> Self {
+        Self {
+            args: args.into_iter().collect::<Vec<_>>().into_iter(),
+        }
+    }
+
+    /// The next argument, if there is one.
+    pub(crate) fn next(&mut self) -> Option<String> {
+        self.args.next()
+    }
+
+    /// The value following a flag such as `--seed`.
+    pub(crate) fn value(&mut self, flag: &str) -> Result<String, CliError> {
+        self.args
+            .next()
+            .ok_or_else(|| CliError::Usage(format!("{flag} requires a value")))
+    }
+
+    /// The value following a flag, parsed.
+    pub(crate) fn parse<T: FromStr>(&mut self, flag: &str) -> Result<T, CliError> {
+        let value = self.value(flag)?;
+        value
+            .parse()
+            .map_err(|_| CliError::Usage(format!("invalid value for {flag}: {value}")))
+    }
+}
+
+/// The error for an argument that a subcommand does not accept.
+pub(crate) fn unexpected(argument: &str) -> CliError {
+    CliError::Usage(format!("unexpected argument: {argument}"))
+}
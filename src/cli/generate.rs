@@ -0,0 +1,272 @@
+use std::path::{Path, PathBuf};
+
+use freeciv_rs::{
+    game::{GameSetup, GameSetupError},
+    save::Savegame,
+    world::{FairnessReport, MapScript, MapScripts, Parameters, ScriptOptions, World},
+};
+
+use super::{
+    args::unexpected, json::Json, path_report, renderer, world_report, write_image, Args, CliError,
+};
+
+/// Tiles around a start position whose resources count towards its fairness:
+/// the tiles a city founded there can work.
+const FAIRNESS_RADIUS: usize = 2;
+
+/// Arguments shared by the commands that generate worlds:
+/// `[--script NAME] [--seed N] [--width N] [--height N] [--specials N]
+/// [--option KEY=VALUE]... [--plugin FILE.wasm]...`
+pub(super) struct MapArgs {
+    pub(super) script: String,
+    pub(super) params: Parameters,
+    pub(super) options: ScriptOptions,
+    /// Map script plugins to load in addition to the built-in scripts.
+    pub(super) plugins: Vec<PathBuf>,
+}
+
+impl Default for MapArgs {
+    fn default() -> Self {
+        Self {
+            script: String::from("fractal"),
+            params: Parameters::default(),
+            options: ScriptOptions::new(),
+            plugins: Vec::new(),
+        }
+    }
+}
+
+impl MapArgs {
+    /// Parses `arg` if it is one of the map arguments. Returns whether it was.
+    pub(super) fn parse(&mut self, arg: &str, args: &mut Args) -> Result<bool, CliError> {
+        match arg {
+            "--script" => self.script = args.value(arg)?,
+            "--seed" => self.params.seed = args.parse(arg)?,
+            "--width" => self.params.width = args.parse(arg)?,
+            "--height" => self.params.height = args.parse(arg)?,
+            "--specials" => self.params.special_frequency = args.parse(arg)?,
+            "--option" => {
+                let option = args.value(arg)?;
+                let (key, value) = option
+                    .split_once('=')
+                    .ok_or_else(|| CliError::Usage(format!("expected KEY=VALUE: {option}")))?;
+                self.options.insert(key.to_owned(), value.to_owned());
+            }
+            "--plugin" => self.plugins.push(args.parse(arg)?),
+            _ => return Ok(false),
+        }
+
+        Ok(true)
+    }
+
+    /// The built-in map scripts and the plugins.
+    pub(super) fn scripts(&self) -> Result<MapScripts, CliError> {
+        let mut scripts = MapScripts::builtin();
+        for path in &self.plugins {
+            scripts.register(load_plugin(path)?)?;
+        }
+
+        Ok(scripts)
+    }
+
+    /// Generates a world with the parsed arguments and the given seed.
+    pub(super) fn generate(&self, scripts: &MapScripts, seed: u32) -> Result<World, CliError> {
+        let params = Parameters {
+            seed,
+            ..self.params.clone()
+        };
+
+        Ok(scripts.generate(&self.script, params, &self.options)?)
+    }
+}
+
+#[cfg(feature = "wasm-plugins")]
+fn load_plugin(path: &Path) -> Result<Box<dyn MapScript>, CliError> {
+    match freeciv_rs::world::WasmScript::load(path) {
+        Ok(script) => Ok(Box::new(script)),
+        Err(error) => Err(freeciv_rs::world::MapScriptError::Failed(error.to_string()).into()),
+    }
+}
+
+#[cfg(not(feature = "wasm-plugins"))]
+fn load_plugin(_: &Path) -> Result<Box<dyn MapScript>, CliError> {
+    Err(CliError::Usage(String::from(
+        "--plugin requires building with the wasm-plugins feature",
+    )))
+}
+
+/// The fairest of several generated worlds, as chosen by [`best_of`].
+struct Fairest {
+    seed: u32,
+    world: World,
+    starts: Vec<(usize, usize)>,
+    report: FairnessReport,
+}
+
+/// Generates a world for `count` seeds, starting at the seed of `map_args`,
+/// and keeps the one with the lowest [`FairnessReport::imbalance`] between the
+/// start positions of `setup`. The earliest seed wins ties.
+///
+/// Seeds without room for every player are skipped. If no seed has room, the
+/// error of the first one is returned.
+fn best_of(
+    map_args: &MapArgs,
+    scripts: &MapScripts,
+    setup: &GameSetup,
+    count: u32,
+) -> Result<Fairest, CliError> {
+    let first = map_args.params.seed;
+    let mut best: Option<Fairest> = None;
+    let mut error = None;
+
+    for seed in (0..count.max(1)).map_while(|offset| first.checked_add(offset)) {
+        let world = map_args.generate(scripts, seed)?;
+        let starts = match setup.start_positions(&world) {
+            Ok(starts) => starts,
+            Err(setup_error) => {
+                error.get_or_insert(setup_error);
+                continue;
+            }
+        };
+        let report = world.analyze_fairness(&starts, FAIRNESS_RADIUS);
+
+        if !matches!(&best, Some(best) if best.report.imbalance() <= report.imbalance()) {
+            best = Some(Fairest {
+                seed,
+                world,
+                starts,
+                report,
+            });
+        }
+    }
+
+    best.ok_or_else(|| {
+        error
+            .unwrap_or(GameSetupError::NotEnoughStartPositions)
+            .into()
+    })
+}
+
+/// `generate [MAP ARGS] [-o OUT.png] [--save OUT.sav] [--minimap]
+/// [--best-of N] [--players N] [--equalize] [--tile-size N]`
+///
+/// Generates a new world, renders it and optionally stores it as a scenario.
+/// With `--best-of` or `--equalize`, the fairness of the start positions is
+/// analyzed and reported; see [`best_of`].
+pub(crate) fn run(mut args: Args) -> Result<Json, CliError> {
+    let mut map_args = MapArgs::default();
+    let mut output = PathBuf::from("map.png");
+    let mut save = None;
+    let mut minimap = false;
+    let mut tile_size = None;
+    let mut count = 1_u32;
+    let mut players = GameSetup::default().players;
+    let mut equalize = false;
+
+    while let Some(arg) = args.next() {
+        if map_args.parse(&arg, &mut args)? {
+            continue;
+        }
+
+        match arg.as_str() {
+            "-o" | "--output" => output = args.parse(&arg)?,
+            "--save" => save = Some(args.parse::<PathBuf>(&arg)?),
+            "--minimap" => minimap = true,
+            "--tile-size" => tile_size = Some(args.parse(&arg)?),
+            "--best-of" => count = args.parse(&arg)?,
+            "--players" => players = args.parse(&arg)?,
+            "--equalize" => equalize = true,
+            _ => return Err(unexpected(&arg)),
+        }
+    }
+
+    let scripts = map_args.scripts()?;
+    let (seed, world, fairness) = if count > 1 || equalize {
+        let setup = GameSetup {
+            players,
+            ..GameSetup::default()
+        };
+        let Fairest {
+            seed,
+            mut world,
+            starts,
+            report,
+        } = best_of(&map_args, &scripts, &setup, count)?;
+        let added = if equalize {
+            world.equalize_specials(&starts, FAIRNESS_RADIUS, &map_args.params.special_weights)
+        } else {
+            0
+        };
+        let fairness = Json::object([
+            ("players", Json::from(u32::from(players))),
+            ("seeds_tried", Json::from(count)),
+            ("imbalance", Json::from(report.imbalance())),
+            ("specials_added", Json::from(added)),
+            (
+                "imbalance_after",
+                Json::from(world.analyze_fairness(&starts, FAIRNESS_RADIUS).imbalance()),
+            ),
+        ]);
+
+        (seed, world, fairness)
+    } else {
+        let seed = map_args.params.seed;
+        (seed, map_args.generate(&scripts, seed)?, Json::Null)
+    };
+
+    let renderer = renderer(&world, tile_size, minimap)?;
+    let image = if minimap {
+        renderer.render_minimap(&world, None)
+    } else {
+        renderer.render(&world)
+    };
+    write_image(&renderer, &image, &output)?;
+
+    let report = Json::object([
+        ("command", Json::from("generate")),
+        ("script", Json::from(map_args.script.as_str())),
+        ("seed", Json::from(seed)),
+        ("output", path_report(&output)),
+        ("save", save.as_deref().map_or(Json::Null, path_report)),
+        ("world", world_report(&world)),
+        ("fairness", fairness),
+    ]);
+
+    if let Some(path) = save {
+        Savegame::scenario(world).save(path)?;
+    }
+
+    Ok(report)
+}
+
+#[test]
+fn test_best_of() {
+    let map_args = MapArgs {
+        params: Parameters {
+            width: 40,
+            height: 25,
+            seed: 3,
+            ..Parameters::default()
+        },
+        ..MapArgs::default()
+    };
+    let scripts = map_args.scripts().unwrap();
+    let setup = GameSetup::default();
+
+    let fairest = best_of(&map_args, &scripts, &setup, 5).unwrap();
+    assert!((3..8).contains(&fairest.seed));
+    assert_eq!(
+        fairest.world.fingerprint(),
+        map_args
+            .generate(&scripts, fairest.seed)
+            .unwrap()
+            .fingerprint()
+    );
+    for seed in 3..8 {
+        let world = map_args.generate(&scripts, seed).unwrap();
+        if let Ok(starts) = setup.start_positions(&world) {
+            let report = world.analyze_fairness(&starts, FAIRNESS_RADIUS);
+            assert!(fairest.report.imbalance() <= report.imbalance());
+        }
+    }
+}
@@ -0,0 +1,256 @@
+mod args;
+mod generate;
+mod json;
+mod render;
+mod scan_seeds;
+
+use std::{
+    collections::BTreeMap,
+    fmt,
+    fs::File,
+    io::{self, BufWriter},
+    path::Path,
+    process::ExitCode,
+};
+
+use freeciv_rs::{
+    game::GameSetupError,
+    save::LoadError,
+    world::{MapScriptError, RenderError, Renderer, World},
+};
+use image::DynamicImage;
+
+use self::{args::Args, json::Json};
+
+pub(crate) const USAGE: &str = "\
+Usage:
+    freeciv-rs [generate] [--script NAME] [--seed N] [--width N] [--height N]
+                          [--specials N] [--option KEY=VALUE]... [-o OUT.png]
+                          [--save OUT.sav] [--minimap] [--plugin FILE.wasm]...
+                          [--best-of N] [--players N] [--equalize]
+                          [--tile-size N]
+    freeciv-rs render <SAVE-OR-SCENARIO> -o OUT.png [--player N] [--minimap]
+                          [--tile-size N]
+    freeciv-rs scan-seeds --seeds START..END -o DIR [--script NAME] [--width N]
+                          [--height N] [--specials N] [--option KEY=VALUE]...
+                          [--contact-sheet] [--plugin FILE.wasm]...
+    freeciv-rs help
+
+--tile-size is the width and height of a tile in pixels. It defaults to the
+size of the tileset's sprites, or 2 with --minimap.
+
+--specials is the per mille of tiles with a special resource, 250 by default.
+
+--best-of N tries N seeds, starting at --seed, and keeps the world where the
+start positions of --players players (2 by default) have the fairest access
+to resources. --equalize then adds specials around the worse start positions.
+
+Plugins are map scripts compiled to WebAssembly, selected with --script and
+the file name without extension. They require the wasm-plugins feature.
+
+Every command accepts --json to print its results and errors as JSON on
+standard output.";
+
+/// The reason why a command failed.
+#[derive(Debug)]
+pub(crate) enum CliError {
+    /// The arguments are invalid.
+    Usage(String),
+    Io(io::Error),
+    Load(LoadError),
+    Png(png::EncodingError),
+    MapScript(MapScriptError),
+    /// No start positions could be chosen to analyze fairness.
+    Setup(GameSetupError),
+    Render(RenderError),
+    /// The savegame has no player with this number.
+    NoSuchPlayer(usize),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Usage(message) => f.write_str(message),
+            Self::Io(error) => error.fmt(f),
+            Self::Load(error) => write!(f, "failed to load: {error}"),
+            Self::Png(error) => write!(f, "failed to write image: {error}"),
+            Self::MapScript(error) => write!(f, "failed to generate: {error}"),
+            Self::Setup(error) => write!(f, "failed to choose start positions: {error}"),
+            Self::Render(error) => write!(f, "failed to render: {error}"),
+            Self::NoSuchPlayer(index) => write!(f, "there is no player {index}"),
+        }
+    }
+}
+
+impl CliError {
+    /// A short identifier of the kind of error for JSON output.
+    const fn kind(&self) -> &'static str {
+        match self {
+            Self::Usage(_) => "usage",
+            Self::Io(_) => "io",
+            Self::Load(_) => "load",
+            Self::Png(_) => "png",
+            Self::MapScript(_) => "map_script",
+            Self::Setup(_) => "setup",
+            Self::Render(_) => "render",
+            Self::NoSuchPlayer(_) => "no_such_player",
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+impl From<io::Error> for CliError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<LoadError> for CliError {
+    fn from(error: LoadError) -> Self {
+        Self::Load(error)
+    }
+}
+
+impl From<png::EncodingError> for CliError {
+    fn from(error: png::EncodingError) -> Self {
+        Self::Png(error)
+    }
+}
+
+impl From<MapScriptError> for CliError {
+    fn from(error: MapScriptError) -> Self {
+        Self::MapScript(error)
+    }
+}
+
+impl From<RenderError> for CliError {
+    fn from(error: RenderError) -> Self {
+        Self::Render(error)
+    }
+}
+
+impl From<GameSetupError> for CliError {
+    fn from(error: GameSetupError) -> Self {
+        Self::Setup(error)
+    }
+}
+
+/// Runs the command line interface with the arguments, without the program
+/// name, and reports the result.
+///
+/// With `--json` anywhere in the arguments, the report of the command or the
+/// error is printed as JSON on standard output. Otherwise errors are printed
+/// on standard error.
+pub(crate) fn main<I: IntoIterator<Item = String>>(args: I) -> ExitCode {
+    let (json, args): (Vec<_>, Vec<_>) = args.into_iter().partition(|arg| arg == "--json");
+    let json = !json.is_empty();
+
+    match run(args) {
+        Ok(report) => {
+            if json {
+                println!("{report}");
+            } else if let Json::String(text) = report {
+                println!("{text}");
+            }
+            ExitCode::SUCCESS
+        }
+        Err(error) if json => {
+            let error = Json::object([
+                ("kind", Json::from(error.kind())),
+                ("message", Json::from(error.to_string())),
+            ]);
+            println!("{}", Json::object([("error", error)]));
+            ExitCode::FAILURE
+        }
+        Err(error) => {
+            eprintln!("error: {error}");
+            if matches!(error, CliError::Usage(_)) {
+                eprintln!("\n{USAGE}");
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Runs the subcommand given by the arguments and returns its report.
+/// Without a subcommand, a map is generated.
+fn run<I: IntoIterator<Item = String>>(args: I) -> Result<Json, CliError> {
+    let mut args = args.into_iter().peekable();
+
+    match args.peek().map(String::as_str) {
+        Some("generate") | None => generate::run(Args::new(args.skip(1))),
+        Some("render") => render::run(Args::new(args.skip(1))),
+        Some("scan-seeds") => scan_seeds::run(Args::new(args.skip(1))),
+        Some("help" | "-h" | "--help") => Ok(Json::from(USAGE)),
+        // Flags without a subcommand are passed on to `generate`.
+        Some(arg) if arg.starts_with('-') => generate::run(Args::new(args)),
+        Some(arg) => Err(CliError::Usage(format!("unknown command: {arg}"))),
+    }
+}
+
+/// Summarizes a world for reports: its size, fingerprint and how many tiles
+/// there are of each terrain.
+fn world_report(world: &World) -> Json {
+    let mut terrain = BTreeMap::new();
+    let mut land = 0;
+    for y in 0..world.height() {
+        for x in 0..world.width() {
+            // SAFETY: It is always within height and width.
+            let tile = unsafe { world.tile_at(x, y).unwrap_unchecked() };
+            *terrain
+                .entry(format!("{:?}", tile.terrain()))
+                .or_insert(0_usize) += 1;
+            land += usize::from(!tile.terrain().is_water());
+        }
+    }
+
+    Json::object([
+        ("width", Json::from(world.width())),
+        ("height", Json::from(world.height())),
+        (
+            "fingerprint",
+            Json::from(format!("{:016x}", world.fingerprint())),
+        ),
+        ("land_tiles", Json::from(land)),
+        (
+            "water_tiles",
+            Json::from(world.width() * world.height() - land),
+        ),
+        (
+            "terrain",
+            Json::Object(
+                terrain
+                    .into_iter()
+                    .map(|(name, count)| (name, Json::from(count)))
+                    .collect(),
+            ),
+        ),
+    ])
+}
+
+/// A renderer for `world` with the tile size given by `--tile-size`, if any.
+/// The tile size applies to minimaps if `minimap` is set.
+fn renderer(world: &World, tile_size: Option<u32>, minimap: bool) -> Result<Renderer, CliError> {
+    let mut renderer = Renderer::default();
+    match (tile_size, minimap) {
+        (Some(size), true) => renderer.minimap_tile_size = size,
+        (Some(size), false) => renderer.tile_size = size,
+        (None, _) => {}
+    }
+    renderer.validate(world)?;
+
+    Ok(renderer)
+}
+
+/// A path for reports.
+fn path_report(path: &Path) -> Json {
+    Json::from(path.display().to_string())
+}
+
+fn write_image(renderer: &Renderer, image: &DynamicImage, path: &Path) -> Result<(), CliError> {
+    let file = BufWriter::new(File::create(path)?);
+    renderer.write_png(image, file)?;
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use std::path::PathBuf;
+
+use freeciv_rs::save::Savegame;
+
+use super::{
+    args::unexpected, json::Json, path_report, renderer, world_report, write_image, Args, CliError,
+};
+
+/// `render <SAVE-OR-SCENARIO> -o OUT.png [--player N] [--minimap] [--tile-size
+/// N]`
+///
+/// Renders a stored world, optionally as known to one of its players.
+pub(crate) fn run(mut args: Args) -> Result<Json, CliError> {
+    let mut input = None;
+    let mut output = None;
+    let mut player = None;
+    let mut minimap = false;
+    let mut tile_size = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-o" | "--output" => output = Some(args.parse::<PathBuf>(&arg)?),
+            "--player" => player = Some(args.parse::<usize>(&arg)?),
+            "--minimap" => minimap = true,
+            "--tile-size" => tile_size = Some(args.parse(&arg)?),
+            _ if input.is_none() && !arg.starts_with('-') => input = Some(PathBuf::from(arg)),
+            _ => return Err(unexpected(&arg)),
+        }
+    }
+
+    let input = input.ok_or_else(|| CliError::Usage(String::from("missing input file")))?;
+    let output = output.ok_or_else(|| CliError::Usage(String::from("missing -o OUT.png")))?;
+
+    let savegame = Savegame::load(&input)?;
+    let map = match player {
+        Some(index) => Some(
+            savegame
+                .game()
+                .and_then(|game| game.player_map(game.players().get(index)?.id()))
+                .ok_or(CliError::NoSuchPlayer(index))?,
+        ),
+        None => None,
+    };
+
+    let world = savegame.world();
+    let renderer = renderer(world, tile_size, minimap)?;
+    let image = match (&map, minimap) {
+        (map, true) => renderer.render_minimap(world, map.as_ref()),
+        (Some(map), false) => renderer.render_for_player(world, map),
+        (None, false) => renderer.render(world),
+    };
+
+    write_image(&renderer, &image, &output)?;
+
+    Ok(Json::object([
+        ("command", Json::from("render")),
+        ("input", path_report(&input)),
+        ("output", path_report(&output)),
+        ("player", Json::from(player)),
+        ("minimap", Json::from(minimap)),
+        ("scenario", Json::from(savegame.is_scenario())),
+        ("turn", Json::from(savegame.turn())),
+        ("world", world_report(world)),
+    ]))
+}
@@ -0,0 +1,128 @@
+use std::{fs, ops::Range, path::PathBuf};
+
+use freeciv_rs::world::Renderer;
+use image::{imageops, DynamicImage, Rgba, RgbaImage};
+
+use super::{
+    args::unexpected, generate::MapArgs, json::Json, path_report, world_report, write_image, Args,
+    CliError,
+};
+
+/// Pixels between the minimaps on a contact sheet.
+const CONTACT_SHEET_GAP: u32 = 4;
+
+/// `scan-seeds --seeds START..END -o DIR [MAP ARGS] [--contact-sheet]`
+///
+/// Generates a world for every seed in the range and writes its minimap to
+/// `DIR/seed-N.png`. With `--contact-sheet`, all minimaps are combined into
+/// `DIR/contact-sheet.png` instead, row by row in the order of the seeds.
+pub(crate) fn run(mut args: Args) -> Result<Json, CliError> {
+    let mut map_args = MapArgs::default();
+    let mut seeds = None;
+    let mut output = None;
+    let mut contact_sheet = false;
+
+    while let Some(arg) = args.next() {
+        if map_args.parse(&arg, &mut args)? {
+            continue;
+        }
+
+        match arg.as_str() {
+            "--seeds" => seeds = Some(parse_range(&args.value(&arg)?)?),
+            "-o" | "--output" => output = Some(args.parse::<PathBuf>(&arg)?),
+            "--contact-sheet" => contact_sheet = true,
+            _ => return Err(unexpected(&arg)),
+        }
+    }
+
+    let seeds = seeds.ok_or_else(|| CliError::Usage(String::from("missing --seeds START..END")))?;
+    let output = output.ok_or_else(|| CliError::Usage(String::from("missing -o DIR")))?;
+    fs::create_dir_all(&output)?;
+
+    let scripts = map_args.scripts()?;
+    let renderer = Renderer::default();
+    let mut minimaps = Vec::new();
+    let mut reports = Vec::new();
+    for seed in seeds {
+        let world = map_args.generate(&scripts, seed)?;
+        let minimap = renderer.render_minimap(&world, None);
+
+        let path = if contact_sheet {
+            minimaps.push(minimap);
+            Json::Null
+        } else {
+            let path = output.join(format!("seed-{seed}.png"));
+            write_image(&renderer, &minimap, &path)?;
+            path_report(&path)
+        };
+        reports.push(Json::object([
+            ("seed", Json::from(seed)),
+            ("output", path),
+            ("world", world_report(&world)),
+        ]));
+    }
+
+    let sheet_path = if contact_sheet {
+        let path = output.join("contact-sheet.png");
+        write_image(&renderer, &combine(&minimaps), &path)?;
+        path_report(&path)
+    } else {
+        Json::Null
+    };
+
+    Ok(Json::object([
+        ("command", Json::from("scan-seeds")),
+        ("script", Json::from(map_args.script.as_str())),
+        ("contact_sheet", sheet_path),
+        ("seeds", Json::Array(reports)),
+    ]))
+}
+
+/// Parses an exclusive range of seeds such as `0..16`.
+fn parse_range(range: &str) -> Result<Range<u32>, CliError> {
+    let invalid = || CliError::Usage(format!("invalid seed range: {range}"));
+
+    let (start, end) = range.split_once("..").ok_or_else(invalid)?;
+    let start = start.parse().map_err(|_| invalid())?;
+    let end = end.parse().map_err(|_| invalid())?;
+    if start >= end {
+        return Err(invalid());
+    }
+
+    Ok(start..end)
+}
+
+/// Arranges equally sized images in a roughly square grid.
+fn combine(images: &[DynamicImage]) -> DynamicImage {
+    let Some(first) = images.first() else {
+        return DynamicImage::new_rgba8(0, 0);
+    };
+
+    let count = u32::try_from(images.len()).unwrap_or(u32::MAX);
+    let columns = (1..=count)
+        .find(|columns| columns * columns >= count)
+        .unwrap_or(count);
+    let rows = count.div_ceil(columns);
+    let cell_width = first.width() + CONTACT_SHEET_GAP;
+    let cell_height = first.height() + CONTACT_SHEET_GAP;
+
+    let mut sheet = RgbaImage::from_pixel(
+        columns * cell_width + CONTACT_SHEET_GAP,
+        rows * cell_height + CONTACT_SHEET_GAP,
+        Rgba([255, 255, 255, 255]),
+    );
+    for (index, image) in (0..count).zip(images) {
+        let x = CONTACT_SHEET_GAP + index % columns * cell_width;
+        let y = CONTACT_SHEET_GAP + index / columns * cell_height;
+        imageops::replace(&mut sheet, &image.to_rgba8(), i64::from(x), i64::from(y));
+    }
+
+    DynamicImage::ImageRgba8(sheet)
+}
+
+#[test]
+fn test_parse_range() {
+    assert_eq!(parse_range("3..7").unwrap(), 3..7);
+    assert!(parse_range("7..3").is_err());
+    assert!(parse_range("5").is_err());
+}
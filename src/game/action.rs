@@ -0,0 +1,749 @@
+use std::fmt;
+
+use super::Game;
+use crate::{
+    cities::{City, CityId},
+    research::{Technology, BRIDGE_BUILDING},
+    tiles::{Flags, Transform, TransformStatus},
+    units::{Activity, MovementDomain, Unit, UnitFlags, UnitId, SINGLE_MOVE},
+    world::Direction,
+};
+
+/// Hitpoints lost by the loser of a single combat round.
+const COMBAT_ROUND_DAMAGE: u8 = 10;
+
+/// An action a unit can take, as returned by [`Game::available_actions`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// Move to the neighbouring tile in a direction. This uses up movement
+    /// depending on the terrain and roads, see
+    /// [`Tile::move_cost`](crate::tiles::Tile::move_cost).
+    Move { direction: Direction },
+    /// Attack the neighbouring tile in a direction. The target must be the
+    /// unit that defends the tile, the enemy with the best defense there.
+    Attack {
+        direction: Direction,
+        target: UnitId,
+    },
+    /// Found a new city on the unit's tile. The unit is used up.
+    FoundCity,
+    /// Start building a road on the unit's tile.
+    BuildRoad,
+    /// Fortify the unit on its tile, making it defend better.
+    Fortify,
+}
+
+/// The reason why a unit cannot perform an [`Action`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActionError {
+    /// The unit does not exist.
+    UnknownUnit,
+    /// Units of this type can never perform this action.
+    NotCapable,
+    /// The unit does not have enough movement left this turn.
+    NotEnoughMovement,
+    /// The target tile is beyond the edge of the map.
+    OffMap,
+    /// The unit cannot enter or work the terrain of the target tile.
+    TerrainImpassable,
+    /// The unit would move from one tile next to enemy units to another one.
+    BlockedByZoc,
+    /// The target tile is occupied by an enemy unit or city.
+    OccupiedByEnemy,
+    /// There is no such enemy unit on the target tile, or it is not the unit
+    /// that defends the tile.
+    NoTarget,
+    /// Another city is too close to found a new one here.
+    TooCloseToCity,
+    /// The action has already been performed or is in progress.
+    AlreadyDone,
+    /// The unit's owner needs to research a technology first.
+    RequiresTech(&'static Technology),
+}
+
+impl fmt::Display for ActionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownUnit => f.write_str("the unit does not exist"),
+            Self::NotCapable => f.write_str("this unit cannot do that"),
+            Self::NotEnoughMovement => f.write_str("not enough movement left this turn"),
+            Self::OffMap => f.write_str("the target is beyond the edge of the map"),
+            Self::TerrainImpassable => f.write_str("the unit cannot go there"),
+            Self::BlockedByZoc => f.write_str("blocked by an enemy zone of control"),
+            Self::OccupiedByEnemy => f.write_str("the tile is occupied by the enemy"),
+            Self::NoTarget => f.write_str("there is nothing to attack"),
+            Self::TooCloseToCity => f.write_str("too close to another city"),
+            Self::AlreadyDone => f.write_str("this has already been done"),
+            Self::RequiresTech(technology) => write!(f, "requires {}", technology.name()),
+        }
+    }
+}
+
+impl std::error::Error for ActionError {}
+
+impl Game {
+    /// Returns every action the unit can currently take. Returns an empty list
+    /// if the unit does not exist.
+    ///
+    /// Clients can offer these actions to users, and AI players can choose
+    /// from them without checking the game rules themselves.
+    #[must_use]
+    pub fn available_actions(&self, id: UnitId) -> Vec<Action> {
+        let Some(unit) = self.units.get(&id) else {
+            return Vec::new();
+        };
+
+        let towards = Direction::ALL.into_iter().filter_map(|direction| {
+            let (x, y) = self.world.step(unit.x, unit.y, direction)?;
+
+            Some(match self.best_defender_against(unit, x, y) {
+                Some(target) => Action::Attack { direction, target },
+                None => Action::Move { direction },
+            })
+        });
+        let on_tile = [Action::FoundCity, Action::BuildRoad, Action::Fortify];
+
+        towards
+            .chain(on_tile)
+            .filter(|&action| self.validate_action(unit, action).is_ok())
+            .collect()
+    }
+
+    /// Checks whether a unit can perform an action right now, without
+    /// performing it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ActionError`] explaining why the action is not possible.
+    pub fn check_action(&self, id: UnitId, action: Action) -> Result<(), ActionError> {
+        let unit = self.units.get(&id).ok_or(ActionError::UnknownUnit)?;
+        self.validate_action(unit, action)
+    }
+
+    /// Performs an action with a unit.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ActionError`] explaining why the action is not possible.
+    /// Nothing is changed in that case.
+    pub fn perform_action(&mut self, id: UnitId, action: Action) -> Result<(), ActionError> {
+        self.check_action(id, action)?;
+
+        match action {
+            Action::Move { direction } => {
+                let unit = &self.units[&id];
+                let (x, y) = self
+                    .world
+                    .step(unit.x, unit.y, direction)
+                    .ok_or(ActionError::OffMap)?;
+                let cost = self.move_cost(unit, x, y);
+
+                // SAFETY: The unit exists, it was checked above.
+                let unit = unsafe { self.units.get_mut(&id).unwrap_unchecked() };
+                unit.x = x;
+                unit.y = y;
+                unit.moves_left = unit.moves_left.saturating_sub(cost);
+                unit.activity = Activity::Idle;
+            }
+            Action::Attack { target, .. } => self.resolve_combat(id, target),
+            Action::FoundCity => {
+                // SAFETY: The unit exists, it was checked above.
+                let unit = unsafe { self.units.remove(&id).unwrap_unchecked() };
+                let city = CityId(self.next_city_id);
+                self.next_city_id += 1;
+                self.cities
+                    .insert(city, City::new(city, unit.owner, unit.x, unit.y));
+            }
+            Action::BuildRoad => {
+                let unit = &self.units[&id];
+                let (x, y) = unit.position();
+                if let Some(mut tile) = self.world.tile_at_mut(x, y) {
+                    if tile.transform_status == TransformStatus::NotTransforming {
+                        tile.start_transform(Transform::Road, &self.speed);
+                    }
+                }
+
+                // SAFETY: The unit exists, it was checked above.
+                unsafe { self.units.get_mut(&id).unwrap_unchecked() }.activity =
+                    Activity::BuildingRoad;
+            }
+            Action::Fortify => {
+                // SAFETY: The unit exists, it was checked above.
+                unsafe { self.units.get_mut(&id).unwrap_unchecked() }.activity =
+                    Activity::Fortifying;
+            }
+        }
+
+        self.update_vision();
+
+        Ok(())
+    }
+
+    fn validate_action(&self, unit: &Unit, action: Action) -> Result<(), ActionError> {
+        match action {
+            Action::Move { direction } => self.validate_move(unit, direction),
+            Action::Attack { direction, target } => self.validate_attack(unit, direction, target),
+            Action::FoundCity => self.validate_found_city(unit),
+            Action::BuildRoad => self.validate_build_road(unit),
+            Action::Fortify => Self::validate_fortify(unit),
+        }
+    }
+
+    /// Returns the tile a unit would step onto in a direction and checks that
+    /// the unit can act on it at all.
+    fn validate_step(
+        &self,
+        unit: &Unit,
+        direction: Direction,
+    ) -> Result<(usize, usize), ActionError> {
+        if unit.moves_left == 0 {
+            return Err(ActionError::NotEnoughMovement);
+        }
+
+        let (x, y) = self
+            .world
+            .step(unit.x, unit.y, direction)
+            .ok_or(ActionError::OffMap)?;
+        let tile = self.world.tile_at(x, y).ok_or(ActionError::OffMap)?;
+
+        if !tile.terrain.passable_for(unit.kind.class()) {
+            return Err(ActionError::TerrainImpassable);
+        }
+
+        Ok((x, y))
+    }
+
+    fn validate_move(&self, unit: &Unit, direction: Direction) -> Result<(), ActionError> {
+        let (x, y) = self.validate_step(unit, direction)?;
+
+        if self.best_defender_against(unit, x, y).is_some()
+            || self
+                .city_at(x, y)
+                .is_some_and(|city| city.owner != unit.owner)
+        {
+            return Err(ActionError::OccupiedByEnemy);
+        }
+
+        if !self.can_step_wrt_zoc(unit, x, y) {
+            return Err(ActionError::BlockedByZoc);
+        }
+
+        if unit.moves_left < self.move_cost(unit, x, y) && !unit.has_full_moves() {
+            return Err(ActionError::NotEnoughMovement);
+        }
+
+        Ok(())
+    }
+
+    fn validate_attack(
+        &self,
+        unit: &Unit,
+        direction: Direction,
+        target: UnitId,
+    ) -> Result<(), ActionError> {
+        if unit.kind.flags().contains(UnitFlags::NON_MILITARY) || unit.kind.attack() == 0 {
+            return Err(ActionError::NotCapable);
+        }
+
+        let (x, y) = self.validate_step(unit, direction)?;
+
+        if self.best_defender_against(unit, x, y) == Some(target) {
+            Ok(())
+        } else {
+            Err(ActionError::NoTarget)
+        }
+    }
+
+    fn validate_found_city(&self, unit: &Unit) -> Result<(), ActionError> {
+        if !unit.kind.flags().contains(UnitFlags::CITIES) {
+            return Err(ActionError::NotCapable);
+        }
+
+        if unit.moves_left == 0 {
+            return Err(ActionError::NotEnoughMovement);
+        }
+
+        if self
+            .world
+            .tile_at(unit.x, unit.y)
+            .is_none_or(|tile| tile.terrain.is_water())
+        {
+            return Err(ActionError::TerrainImpassable);
+        }
+
+        if self
+            .cities
+            .values()
+            .any(|city| self.world.distance(city.position(), unit.position()) <= 1)
+        {
+            return Err(ActionError::TooCloseToCity);
+        }
+
+        Ok(())
+    }
+
+    fn validate_build_road(&self, unit: &Unit) -> Result<(), ActionError> {
+        if !unit.kind.flags().contains(UnitFlags::SETTLERS) {
+            return Err(ActionError::NotCapable);
+        }
+
+        if unit.moves_left == 0 {
+            return Err(ActionError::NotEnoughMovement);
+        }
+
+        let tile = self
+            .world
+            .tile_at(unit.x, unit.y)
+            .ok_or(ActionError::OffMap)?;
+
+        if !tile.terrain.can_build_road() {
+            return Err(ActionError::TerrainImpassable);
+        }
+
+        if unit.activity == Activity::BuildingRoad || tile.flags.contains(Flags::HAS_ROAD) {
+            return Err(ActionError::AlreadyDone);
+        }
+
+        // Roads on rivers need bridges.
+        if tile.flags.contains(Flags::HAS_RIVER)
+            && !self
+                .player(unit.owner)
+                .is_some_and(|player| player.technologies.has_researched(&BRIDGE_BUILDING))
+        {
+            return Err(ActionError::RequiresTech(&BRIDGE_BUILDING));
+        }
+
+        Ok(())
+    }
+
+    const fn validate_fortify(unit: &Unit) -> Result<(), ActionError> {
+        if unit.kind.flags().contains(UnitFlags::NON_MILITARY)
+            || !matches!(unit.kind.class().domain(), MovementDomain::Land)
+        {
+            return Err(ActionError::NotCapable);
+        }
+
+        if matches!(unit.activity, Activity::Fortifying | Activity::Fortified) {
+            return Err(ActionError::AlreadyDone);
+        }
+
+        Ok(())
+    }
+
+    /// Whether a unit may step onto the tile at `x`, `y` with respect to zones
+    /// of control, following FreeCiv's rules: Land units may not move directly
+    /// from one tile next to an enemy military unit to another one, unless
+    /// they move into a city or onto a tile with their own units.
+    fn can_step_wrt_zoc(&self, unit: &Unit, x: usize, y: usize) -> bool {
+        if unit.kind.flags().contains(UnitFlags::IGZOC)
+            || unit.kind.class().domain() != MovementDomain::Land
+        {
+            return true;
+        }
+
+        if self.units_at(x, y).any(|other| other.owner == unit.owner)
+            || self.city_at(unit.x, unit.y).is_some()
+            || self.city_at(x, y).is_some()
+        {
+            return true;
+        }
+
+        self.is_free_of_enemy_zoc(unit, unit.x, unit.y) || self.is_free_of_enemy_zoc(unit, x, y)
+    }
+
+    /// Whether no enemy military land unit is next to the tile at `x`, `y`.
+    fn is_free_of_enemy_zoc(&self, unit: &Unit, x: usize, y: usize) -> bool {
+        self.world.tiles_within(x, y, 1).all(|tile| {
+            let (nx, ny) = tile.position();
+
+            tile.terrain.is_water()
+                || !self.units_at(nx, ny).any(|other| {
+                    other.owner != unit.owner
+                        && other.kind.class().domain() == MovementDomain::Land
+                        && !other.kind.flags().contains(UnitFlags::NON_MILITARY)
+                })
+        })
+    }
+
+    /// The cost of moving a unit from its tile onto the tile at `x`, `y`, in
+    /// fragments of [`SINGLE_MOVE`].
+    fn move_cost(&self, unit: &Unit, x: usize, y: usize) -> u8 {
+        let flags = unit.kind.flags();
+        if flags.contains(UnitFlags::IGTER) {
+            return 1;
+        }
+
+        if unit.kind.class().domain() != MovementDomain::Land {
+            return SINGLE_MOVE;
+        }
+
+        let (Some(from), Some(to)) = (self.world.tile_at(unit.x, unit.y), self.world.tile_at(x, y))
+        else {
+            return SINGLE_MOVE;
+        };
+
+        if from.flags.contains(Flags::HAS_ROAD) && to.flags.contains(Flags::HAS_ROAD) {
+            1
+        } else {
+            to.move_cost() * SINGLE_MOVE
+        }
+    }
+
+    /// The enemy unit on the tile at `x`, `y` that would defend against an
+    /// attack by `unit`, if there are any enemies.
+    fn best_defender_against(&self, unit: &Unit, x: usize, y: usize) -> Option<UnitId> {
+        self.units_at(x, y)
+            .filter(|other| other.owner != unit.owner)
+            .max_by_key(|other| u16::from(other.kind.defense()) * u16::from(other.hitpoints))
+            .map(Unit::id)
+    }
+
+    /// Fights rounds between two units until one of them has no hitpoints
+    /// left. The loser is removed. If the defender loses outside of a city,
+    /// all other units on its tile are destroyed as well.
+    fn resolve_combat(&mut self, attacker: UnitId, defender: UnitId) {
+        let (Some(attacking), Some(defending)) =
+            (self.units.get(&attacker), self.units.get(&defender))
+        else {
+            return;
+        };
+
+        let attack = f64::from(attacking.kind.attack());
+        let defense = f64::from(defending.kind.defense())
+            * if defending.activity == Activity::Fortified {
+                1.5
+            } else {
+                1.0
+            };
+        let win_chance = attack / (attack + defense);
+
+        let mut attacker_hp = attacking.hitpoints;
+        let mut defender_hp = defending.hitpoints;
+        while attacker_hp > 0 && defender_hp > 0 {
+            if self.rng.f64() < win_chance {
+                defender_hp = defender_hp.saturating_sub(COMBAT_ROUND_DAMAGE);
+            } else {
+                attacker_hp = attacker_hp.saturating_sub(COMBAT_ROUND_DAMAGE);
+            }
+        }
+
+        if attacker_hp == 0 {
+            self.units.remove(&attacker);
+            if let Some(unit) = self.units.get_mut(&defender) {
+                unit.hitpoints = defender_hp;
+            }
+
+            return;
+        }
+
+        if let Some(unit) = self.units.get_mut(&attacker) {
+            unit.hitpoints = attacker_hp;
+            unit.moves_left = unit.moves_left.saturating_sub(SINGLE_MOVE);
+        }
+
+        // SAFETY: The defender exists, it was checked above.
+        let defeated = unsafe { self.units.remove(&defender).unwrap_unchecked() };
+        if self.city_at(defeated.x, defeated.y).is_none() {
+            self.units
+                .retain(|_, unit| unit.position() != defeated.position());
+        }
+    }
+
+    /// Ends the current turn. Units regain their movement, fortifying units
+    /// become fortified and terrain work progresses.
+    pub fn end_turn(&mut self) {
+        let mut worked_tiles: Vec<(usize, usize)> = self
+            .units
+            .values()
+            .filter(|unit| unit.activity == Activity::BuildingRoad)
+            .map(Unit::position)
+            .collect();
+        worked_tiles.sort_unstable();
+        worked_tiles.dedup();
+
+        for (x, y) in worked_tiles {
+            let Some(mut tile) = self.world.tile_at_mut(x, y) else {
+                continue;
+            };
+            tile.tick_transform();
+
+            if tile.transform_status == TransformStatus::NotTransforming {
+                for unit in self.units.values_mut() {
+                    if unit.position() == (x, y) && unit.activity == Activity::BuildingRoad {
+                        unit.activity = Activity::Idle;
+                    }
+                }
+            }
+        }
+
+        for unit in self.units.values_mut() {
+            if unit.activity == Activity::Fortifying {
+                unit.activity = Activity::Fortified;
+            }
+            unit.moves_left = unit.kind.moves() * SINGLE_MOVE;
+        }
+
+        self.turn += 1;
+        self.update_vision();
+    }
+}
+
+#[test]
+fn test_available_actions() {
+    use super::GameSetup;
+    use crate::{
+        tiles::Terrain,
+        units::{PHALANX, SETTLERS, TRIREME, WARRIORS, WORKERS},
+        world::{generate, Parameters},
+    };
+
+    let mut world = generate(Parameters {
+        width: 8,
+        height: 8,
+        wrapping_x: false,
+        ..Parameters::default()
+    })
+    .unwrap();
+    for y in 0..8 {
+        for x in 0..8 {
+            let mut tile = world.tile_at_mut(x, y).unwrap();
+            tile.terrain = if x == 7 {
+                Terrain::Ocean
+            } else {
+                Terrain::Grassland
+            };
+            tile.flags = Flags::empty();
+        }
+    }
+
+    let mut game = Game::new(
+        world,
+        &GameSetup {
+            players: 2,
+            start_units: String::new(),
+            ..GameSetup::default()
+        },
+    )
+    .unwrap();
+    let first = game.players()[0].id();
+    let second = game.players()[1].id();
+
+    let settlers = game.spawn_unit(first, &SETTLERS, (6, 3));
+    let actions = game.available_actions(settlers);
+    assert!(actions.contains(&Action::FoundCity));
+    assert!(actions.contains(&Action::BuildRoad));
+    assert!(!actions.contains(&Action::Fortify));
+    assert!(actions.contains(&Action::Move {
+        direction: Direction::West,
+    }));
+    assert!(!actions.iter().any(|action| matches!(
+        action,
+        Action::Move {
+            direction: Direction::East,
+            ..
+        }
+    )));
+
+    let workers = game.spawn_unit(first, &WORKERS, (2, 2));
+    assert!(game.available_actions(workers).contains(&Action::BuildRoad));
+
+    let warriors = game.spawn_unit(first, &WARRIORS, (4, 4));
+    let enemy = game.spawn_unit(second, &WARRIORS, (5, 4));
+    let actions = game.available_actions(warriors);
+    assert!(actions.contains(&Action::Fortify));
+    assert!(actions.contains(&Action::Attack {
+        direction: Direction::East,
+        target: enemy,
+    }));
+
+    // Only the unit that defends the tile can be attacked
+    let phalanx = game.spawn_unit(second, &PHALANX, (5, 4));
+    assert_eq!(
+        game.check_action(
+            warriors,
+            Action::Attack {
+                direction: Direction::East,
+                target: enemy,
+            }
+        ),
+        Err(ActionError::NoTarget)
+    );
+    assert!(game.available_actions(warriors).contains(&Action::Attack {
+        direction: Direction::East,
+        target: phalanx,
+    }));
+
+    assert_eq!(game.perform_action(settlers, Action::FoundCity), Ok(()));
+    assert!(game.unit(settlers).is_none());
+    assert!(game.city_at(6, 3).is_some());
+    assert_eq!(
+        game.perform_action(settlers, Action::FoundCity),
+        Err(ActionError::UnknownUnit)
+    );
+
+    let trireme = game.spawn_unit(first, &TRIREME, (7, 0));
+    let actions = game.available_actions(trireme);
+    assert!(actions.contains(&Action::Move {
+        direction: Direction::South,
+    }));
+    assert!(!actions.contains(&Action::Fortify));
+    assert_eq!(
+        game.check_action(
+            trireme,
+            Action::Move {
+                direction: Direction::West,
+            }
+        ),
+        Err(ActionError::TerrainImpassable)
+    );
+}
+
+#[test]
+fn test_action_errors() {
+    use super::GameSetup;
+    use crate::{
+        tiles::Terrain,
+        units::{EXPLORER, WARRIORS, WORKERS},
+        world::{generate, Parameters},
+    };
+
+    let mut world = generate(Parameters {
+        width: 8,
+        height: 8,
+        wrapping_x: false,
+        ..Parameters::default()
+    })
+    .unwrap();
+    for y in 0..8 {
+        for x in 0..8 {
+            let mut tile = world.tile_at_mut(x, y).unwrap();
+            tile.terrain = Terrain::Grassland;
+            tile.flags = if (x, y) == (1, 1) {
+                Flags::HAS_RIVER
+            } else {
+                Flags::empty()
+            };
+        }
+    }
+
+    let mut game = Game::new(
+        world,
+        &GameSetup {
+            players: 2,
+            start_units: String::new(),
+            ..GameSetup::default()
+        },
+    )
+    .unwrap();
+    let first = game.players()[0].id();
+    let second = game.players()[1].id();
+
+    let workers = game.spawn_unit(first, &WORKERS, (1, 1));
+    assert_eq!(
+        game.check_action(workers, Action::BuildRoad),
+        Err(ActionError::RequiresTech(&BRIDGE_BUILDING))
+    );
+    assert_eq!(
+        game.check_action(workers, Action::FoundCity),
+        Err(ActionError::NotCapable)
+    );
+
+    let warriors = game.spawn_unit(first, &WARRIORS, (4, 4));
+    game.spawn_unit(second, &WARRIORS, (5, 4));
+    let north = Action::Move {
+        direction: Direction::North,
+    };
+    assert_eq!(
+        game.check_action(warriors, north),
+        Err(ActionError::BlockedByZoc)
+    );
+    assert_eq!(
+        game.check_action(
+            warriors,
+            Action::Move {
+                direction: Direction::East,
+            }
+        ),
+        Err(ActionError::OccupiedByEnemy)
+    );
+    assert!(game
+        .check_action(
+            warriors,
+            Action::Move {
+                direction: Direction::West,
+            }
+        )
+        .is_ok());
+
+    let explorer = game.spawn_unit(first, &EXPLORER, (4, 4));
+    assert!(game.perform_action(explorer, north).is_ok());
+    assert!(game.perform_action(warriors, north).is_ok());
+    assert_eq!(game.perform_action(warriors, Action::Fortify), Ok(()));
+    assert_eq!(
+        game.perform_action(warriors, Action::Fortify),
+        Err(ActionError::AlreadyDone)
+    );
+}
+
+#[test]
+fn test_shared_work() {
+    use super::GameSetup;
+    use crate::{
+        tiles::Terrain,
+        units::WORKERS,
+        world::{generate, Parameters},
+    };
+
+    let mut world = generate(Parameters {
+        width: 8,
+        height: 8,
+        ..Parameters::default()
+    })
+    .unwrap();
+    for y in 0..8 {
+        for x in 0..8 {
+            let mut tile = world.tile_at_mut(x, y).unwrap();
+            tile.terrain = Terrain::Grassland;
+            tile.flags = Flags::empty();
+        }
+    }
+
+    let mut game = Game::new(
+        world,
+        &GameSetup {
+            players: 1,
+            start_units: String::new(),
+            ..GameSetup::default()
+        },
+    )
+    .unwrap();
+    let player = game.players()[0].id();
+
+    // The workers sharing a tile are not next to each other in id order.
+    let workers = [
+        game.spawn_unit(player, &WORKERS, (2, 2)),
+        game.spawn_unit(player, &WORKERS, (5, 5)),
+        game.spawn_unit(player, &WORKERS, (2, 2)),
+    ];
+    for id in workers {
+        assert_eq!(game.perform_action(id, Action::BuildRoad), Ok(()));
+    }
+
+    // A road on grassland takes two turns, no matter how many workers build it.
+    game.end_turn();
+    let has_road = |game: &Game, x, y| {
+        game.world()
+            .tile_at(x, y)
+            .unwrap()
+            .flags
+            .contains(Flags::HAS_ROAD)
+    };
+    assert!(!has_road(&game, 2, 2));
+    assert!(!has_road(&game, 5, 5));
+    game.end_turn();
+    assert!(has_road(&game, 2, 2));
+    assert!(has_road(&game, 5, 5));
+    assert!(workers
+        .iter()
+        .all(|&id| game.unit(id).unwrap().activity() == Activity::Idle));
+}
@@ -0,0 +1,111 @@
+use super::{Game, PlayerId};
+use crate::{
+    research::ALL_TECHNOLOGIES,
+    units::{Activity, UnitId},
+};
+
+/// Something a player probably wants to take care of before ending the turn,
+/// as returned by [`Game::turn_checklist`].
+///
+/// TODO: Cities with an empty build queue and cities in disorder, once cities
+/// have production and happiness.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChecklistItem {
+    /// The unit has moves left, but no orders.
+    IdleUnit(UnitId),
+    /// The player is not researching anything, although there are
+    /// technologies left to research.
+    ResearchNotSet,
+}
+
+impl Game {
+    /// Lists what a player has not taken care of this turn, like the advisor
+    /// of FreeCiv's clients when pressing "Turn Done". Idle units come first,
+    /// in the order of their ids.
+    ///
+    /// Clients can use this to point users to their idle units, and AI players
+    /// can check it before ending their turn. Observers and players that do
+    /// not exist have nothing to do.
+    #[must_use]
+    pub fn turn_checklist(&self, id: PlayerId) -> Vec<ChecklistItem> {
+        let Some(player) = self.player(id).filter(|player| !player.is_observer()) else {
+            return Vec::new();
+        };
+
+        let idle_units = self
+            .units
+            .values()
+            .filter(|unit| {
+                unit.owner == id && unit.activity == Activity::Idle && unit.moves_left > 0
+            })
+            .map(|unit| ChecklistItem::IdleUnit(unit.id));
+
+        let research_not_set = player.researching.is_none()
+            && ALL_TECHNOLOGIES
+                .iter()
+                .any(|&technology| player.technologies.can_research(technology));
+
+        idle_units
+            .chain(research_not_set.then_some(ChecklistItem::ResearchNotSet))
+            .collect()
+    }
+}
+
+#[test]
+fn test_turn_checklist() {
+    use super::{Action, GameSetup, ResearchError};
+    use crate::{
+        research::{ALPHABET, MAP_MAKING},
+        world::{generate, Parameters},
+    };
+
+    let world = generate(Parameters {
+        width: 16,
+        height: 16,
+        ..Parameters::default()
+    })
+    .unwrap();
+    let mut game = Game::new(
+        world,
+        &GameSetup {
+            players: 2,
+            start_units: String::from("dd"),
+            ..GameSetup::default()
+        },
+    )
+    .unwrap();
+    let player = game.players()[0].id();
+    let units: Vec<UnitId> = game
+        .units()
+        .filter(|unit| unit.owner() == player)
+        .map(|unit| unit.id())
+        .collect();
+    assert_eq!(units.len(), 2);
+
+    assert_eq!(
+        game.turn_checklist(player),
+        [
+            ChecklistItem::IdleUnit(units[0]),
+            ChecklistItem::IdleUnit(units[1]),
+            ChecklistItem::ResearchNotSet,
+        ]
+    );
+
+    assert_eq!(
+        game.set_research(player, &MAP_MAKING),
+        Err(ResearchError::MissingRequirement(&ALPHABET))
+    );
+    game.set_research(player, &ALPHABET).unwrap();
+    assert_eq!(game.players()[0].researching(), Some(&ALPHABET));
+    game.perform_action(units[0], Action::Fortify).unwrap();
+    assert_eq!(
+        game.turn_checklist(player),
+        [ChecklistItem::IdleUnit(units[1])]
+    );
+
+    assert_eq!(
+        game.set_research(PlayerId(7), &ALPHABET),
+        Err(ResearchError::UnknownPlayer)
+    );
+    assert!(game.turn_checklist(PlayerId(7)).is_empty());
+}
@@ -0,0 +1,40 @@
+//! Games played by several players on a [`World`](crate::world::World).
+//!
+//! ```
+//! use freeciv_rs::{
+//!     game::{Game, GameSetup},
+//!     world::{generate, Parameters},
+//! };
+//!
+//! let world = generate(Parameters {
+//!     width: 40,
+//!     height: 25,
+//!     ..Parameters::default()
+//! })
+//! .unwrap();
+//! let mut game = Game::new(world, &GameSetup::default()).unwrap();
+//! for unit in game.units() {
+//!     println!("{} at {:?}", unit.kind().name(), unit.position());
+//! }
+//! assert_eq!(game.turn(), 1);
+//! game.end_turn();
+//! assert_eq!(game.turn(), 2);
+//! ```
+
+mod action;
+mod checklist;
+mod player;
+mod player_map;
+mod science;
+mod setup;
+mod speed;
+mod state;
+
+pub use action::{Action, ActionError};
+pub use checklist::ChecklistItem;
+pub use player::{ObserverVision, Player, PlayerId, PlayerKind};
+pub use player_map::PlayerMap;
+pub use science::ResearchError;
+pub use setup::{GameSetup, GameSetupError, StartUnit};
+pub use speed::{GameSpeed, SpeedModifiers};
+pub use state::Game;
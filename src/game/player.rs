@@ -0,0 +1,71 @@
+use super::PlayerMap;
+use crate::research::{Technologies, Technology};
+
+/// Unique identifier of a [`Player`] within a game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PlayerId(pub(crate) u8);
+
+/// Whether a player takes part in the game or only watches it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlayerKind {
+    /// A player with a civilization and units.
+    Participant,
+    /// A spectator without a civilization, for example a replay viewer or a
+    /// spectator connected to a server.
+    Observer(ObserverVision),
+}
+
+/// What an observer can see.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ObserverVision {
+    /// The whole world is visible.
+    Full,
+    /// Everything the given players know or see.
+    Players(Vec<PlayerId>),
+}
+
+/// A player in a game, either a participant or an observer.
+#[derive(Clone)]
+pub struct Player {
+    pub(crate) id: PlayerId,
+    pub(crate) kind: PlayerKind,
+    pub(crate) start_position: Option<(usize, usize)>,
+    pub(crate) technologies: Technologies,
+    pub(crate) researching: Option<&'static Technology>,
+    pub(crate) map: PlayerMap,
+}
+
+impl Player {
+    #[must_use]
+    pub const fn id(&self) -> PlayerId {
+        self.id
+    }
+
+    #[must_use]
+    pub const fn kind(&self) -> &PlayerKind {
+        &self.kind
+    }
+
+    #[must_use]
+    pub const fn is_observer(&self) -> bool {
+        matches!(self.kind, PlayerKind::Observer(_))
+    }
+
+    /// The tile this player's starting units were placed on. Observers have no
+    /// start position.
+    #[must_use]
+    pub const fn start_position(&self) -> Option<(usize, usize)> {
+        self.start_position
+    }
+
+    #[must_use]
+    pub const fn technologies(&self) -> &Technologies {
+        &self.technologies
+    }
+
+    /// The technology the player is currently researching, if any.
+    #[must_use]
+    pub const fn researching(&self) -> Option<&'static Technology> {
+        self.researching
+    }
+}
@@ -0,0 +1,100 @@
+use fixedbitset::FixedBitSet;
+
+/// How many tiles around a unit are visible to its owner.
+pub(crate) const VISION_RADIUS: usize = 1;
+
+/// How many tiles around a city are visible to its owner.
+pub(crate) const CITY_VISION_RADIUS: usize = 2;
+
+/// A player's knowledge of the world.
+///
+/// Tiles are either unknown, known (seen at some point, but not currently
+/// watched by any unit) or visible (currently watched by a unit). Visible tiles
+/// are always known. For every known tile, the map also remembers the turn it
+/// was last seen in.
+#[derive(Debug, Clone)]
+pub struct PlayerMap {
+    width: usize,
+    known: FixedBitSet,
+    visible: FixedBitSet,
+    last_seen: Vec<Option<u32>>,
+}
+
+impl PlayerMap {
+    /// Creates a map where no tile is known.
+    #[must_use]
+    pub fn unknown(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            known: FixedBitSet::with_capacity(width * height),
+            visible: FixedBitSet::with_capacity(width * height),
+            last_seen: vec![None; width * height],
+        }
+    }
+
+    /// Creates a map where every tile is known and visible, seen in `turn`.
+    #[must_use]
+    pub fn full(width: usize, height: usize, turn: u32) -> Self {
+        let mut map = Self::unknown(width, height);
+        map.known.insert_range(..);
+        map.visible.insert_range(..);
+        map.last_seen.fill(Some(turn));
+
+        map
+    }
+
+    #[must_use]
+    pub fn is_known(&self, x: usize, y: usize) -> bool {
+        self.known.contains(y * self.width + x)
+    }
+
+    #[must_use]
+    pub fn is_visible(&self, x: usize, y: usize) -> bool {
+        self.visible.contains(y * self.width + x)
+    }
+
+    /// The turn the tile was last seen in, or `None` if it is unknown.
+    #[must_use]
+    pub fn last_seen(&self, x: usize, y: usize) -> Option<u32> {
+        self.last_seen.get(y * self.width + x).copied().flatten()
+    }
+
+    /// How many turns ago the tile was last seen, or `None` if it is unknown.
+    /// Visible tiles have an age of 0.
+    #[must_use]
+    pub fn intel_age(&self, x: usize, y: usize, turn: u32) -> Option<u32> {
+        self.last_seen(x, y)
+            .map(|last_seen| turn.saturating_sub(last_seen))
+    }
+
+    /// Marks a tile as known and currently visible in `turn`.
+    pub(crate) fn see(&mut self, x: usize, y: usize, turn: u32) {
+        let index = y * self.width + x;
+        self.known.insert(index);
+        self.visible.insert(index);
+        self.last_seen[index] = Some(turn);
+    }
+
+    /// Marks a tile as known, but not currently visible, last seen in `turn`.
+    pub(crate) fn remember(&mut self, x: usize, y: usize, turn: u32) {
+        let index = y * self.width + x;
+        self.known.insert(index);
+        self.last_seen[index] = Some(turn);
+    }
+
+    /// Forgets which tiles are currently visible, keeping them known.
+    pub(crate) fn clear_visible(&mut self) {
+        self.visible.clear();
+    }
+
+    /// Adds everything known or visible on another map to this one, keeping
+    /// the most recent turn each tile was seen in.
+    pub(crate) fn merge(&mut self, other: &Self) {
+        self.known.union_with(&other.known);
+        self.visible.union_with(&other.visible);
+
+        for (last_seen, other) in self.last_seen.iter_mut().zip(&other.last_seen) {
+            *last_seen = (*last_seen).max(*other);
+        }
+    }
+}
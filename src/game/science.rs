@@ -0,0 +1,64 @@
+use std::fmt;
+
+use super::{Game, PlayerId};
+use crate::research::Technology;
+
+/// The reason why a player cannot research a technology.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResearchError {
+    /// The player does not exist or is an observer.
+    UnknownPlayer,
+    /// The player already knows the technology.
+    AlreadyResearched,
+    /// A requirement of the technology has not been researched yet.
+    MissingRequirement(&'static Technology),
+}
+
+impl fmt::Display for ResearchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownPlayer => f.write_str("the player does not exist"),
+            Self::AlreadyResearched => f.write_str("the technology is already researched"),
+            Self::MissingRequirement(technology) => {
+                write!(f, "requires {} first", technology.name())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ResearchError {}
+
+impl Game {
+    /// Sets the technology a player researches.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ResearchError`] if the player cannot research the
+    /// technology. The current research is kept in that case.
+    pub fn set_research(
+        &mut self,
+        id: PlayerId,
+        technology: &'static Technology,
+    ) -> Result<(), ResearchError> {
+        let player = self
+            .players
+            .iter_mut()
+            .find(|player| player.id == id && !player.is_observer())
+            .ok_or(ResearchError::UnknownPlayer)?;
+
+        if player.technologies.has_researched(technology) {
+            return Err(ResearchError::AlreadyResearched);
+        }
+        if let Some(&requirement) = technology
+            .requirements()
+            .iter()
+            .find(|&&requirement| !player.technologies.has_researched(requirement))
+        {
+            return Err(ResearchError::MissingRequirement(requirement));
+        }
+
+        player.researching = Some(technology);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,220 @@
+use std::fmt;
+
+use super::SpeedModifiers;
+use crate::{
+    tiles::Terrain,
+    units::{
+        UnitType, ARCHERS, DIPLOMAT, EXPLORER, HORSEMEN, LEADER, PHALANX, SETTLERS, TRIREME,
+        WARRIORS, WORKERS,
+    },
+    world::World,
+};
+
+/// Options for creating a [`Game`](super::Game) from a [`World`].
+pub struct GameSetup {
+    /// Number of players taking part in the game.
+    pub players: u8,
+    /// Units each player starts with, one letter per unit, like FreeCiv's
+    /// `startunits` setting. See [`StartUnit`] for the meaning of the letters.
+    pub start_units: String,
+    /// Seed for random events during the game, such as combat.
+    pub seed: u64,
+    /// Scales transform turns, research and production costs. See
+    /// [`GameSpeed`](super::GameSpeed) for the predefined speeds.
+    pub speed: SpeedModifiers,
+}
+
+impl Default for GameSetup {
+    fn default() -> Self {
+        Self {
+            players: 2,
+            start_units: String::from("ccwwx"),
+            seed: 0,
+            speed: SpeedModifiers::default(),
+        }
+    }
+}
+
+impl GameSetup {
+    /// Parses [`GameSetup::start_units`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GameSetupError::UnknownStartUnit`] for letters that do not
+    /// correspond to a [`StartUnit`].
+    pub fn parse_start_units(&self) -> Result<Vec<StartUnit>, GameSetupError> {
+        self.start_units.chars().map(StartUnit::try_from).collect()
+    }
+
+    /// Checks that there is at least one player and that the start units are
+    /// valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GameSetupError::NoPlayers`] or
+    /// [`GameSetupError::UnknownStartUnit`].
+    pub fn validate(&self) -> Result<(), GameSetupError> {
+        if self.players == 0 {
+            return Err(GameSetupError::NoPlayers);
+        }
+        self.parse_start_units()?;
+
+        Ok(())
+    }
+
+    /// The start positions the players would get in a game on this world,
+    /// for example to analyze them with
+    /// [`World::analyze_fairness`](crate::world::World::analyze_fairness)
+    /// before starting the game.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`GameSetupError`] if the setup is invalid or the world does
+    /// not have room for all players.
+    pub fn start_positions(&self, world: &World) -> Result<Vec<(usize, usize)>, GameSetupError> {
+        self.validate()?;
+
+        choose_start_positions(world, self.players)
+    }
+}
+
+/// A role of a starting unit. The letters are the same as in FreeCiv's
+/// `startunits` setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StartUnit {
+    /// `c`: City founder.
+    CityFounder,
+    /// `w`: Terrain worker.
+    Worker,
+    /// `x`: Explorer.
+    Explorer,
+    /// `d`: Ok defense unit.
+    Defender,
+    /// `D`: Good defense unit.
+    GoodDefender,
+    /// `a`: Fast attack unit.
+    FastAttacker,
+    /// `A`: Strong attack unit.
+    StrongAttacker,
+    /// `f`: Ferryboat. Placed on water next to the start position.
+    Ferryboat,
+    /// `k`: Gameloss unit, the leader of the civilization.
+    Leader,
+    /// `s`: Diplomat.
+    Diplomat,
+}
+
+impl StartUnit {
+    /// The unit type that is created for this role.
+    #[must_use]
+    pub const fn unit_type(self) -> &'static UnitType {
+        match self {
+            Self::CityFounder => &SETTLERS,
+            Self::Worker => &WORKERS,
+            Self::Explorer => &EXPLORER,
+            Self::Defender => &WARRIORS,
+            Self::GoodDefender => &PHALANX,
+            Self::FastAttacker => &HORSEMEN,
+            Self::StrongAttacker => &ARCHERS,
+            Self::Ferryboat => &TRIREME,
+            Self::Leader => &LEADER,
+            Self::Diplomat => &DIPLOMAT,
+        }
+    }
+}
+
+impl TryFrom<char> for StartUnit {
+    type Error = GameSetupError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
+            'c' => Self::CityFounder,
+            'w' => Self::Worker,
+            'x' => Self::Explorer,
+            'd' => Self::Defender,
+            'D' => Self::GoodDefender,
+            'a' => Self::FastAttacker,
+            'A' => Self::StrongAttacker,
+            'f' => Self::Ferryboat,
+            'k' => Self::Leader,
+            's' => Self::Diplomat,
+            other => return Err(GameSetupError::UnknownStartUnit(other)),
+        })
+    }
+}
+
+/// The reason why a [`Game`](super::Game) could not be created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameSetupError {
+    /// A game needs at least one player.
+    NoPlayers,
+    /// The start units contain a letter that is not a [`StartUnit`].
+    UnknownStartUnit(char),
+    /// The world does not have enough suitable land for every player.
+    NotEnoughStartPositions,
+}
+
+impl fmt::Display for GameSetupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoPlayers => f.write_str("a game needs at least one player"),
+            Self::UnknownStartUnit(letter) => write!(f, "unknown start unit '{letter}'"),
+            Self::NotEnoughStartPositions => {
+                f.write_str("not enough suitable land for every player")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GameSetupError {}
+
+/// Whether a terrain is good enough to start a civilization on.
+const fn is_start_terrain(terrain: Terrain) -> bool {
+    matches!(
+        terrain,
+        Terrain::Grassland | Terrain::Plains | Terrain::Hills
+    )
+}
+
+/// Chooses a start position for each player.
+///
+/// The first position is the suitable tile with the most land around it, every
+/// following position is the suitable tile farthest away from all previously
+/// chosen ones. The result only depends on the world, so the same world always
+/// yields the same start positions.
+pub(crate) fn choose_start_positions(
+    world: &World,
+    players: u8,
+) -> Result<Vec<(usize, usize)>, GameSetupError> {
+    let land_around = |x: usize, y: usize| {
+        world
+            .tiles_within(x, y, 2)
+            .filter(|tile| !tile.terrain.is_water())
+            .count()
+    };
+
+    let candidates: Vec<((usize, usize), usize)> = (0..world.height)
+        .flat_map(|y| (0..world.width).map(move |x| (x, y)))
+        .filter(|&(x, y)| is_start_terrain(world.grid[y][x].terrain))
+        .map(|(x, y)| ((x, y), land_around(x, y)))
+        .collect();
+
+    let mut chosen: Vec<(usize, usize)> = Vec::with_capacity(usize::from(players));
+    for _ in 0..players {
+        let best = candidates
+            .iter()
+            .filter(|(position, _)| !chosen.contains(position))
+            .max_by_key(|&&(position, land)| {
+                let distance = chosen
+                    .iter()
+                    .map(|&other| world.distance(position, other))
+                    .min()
+                    .unwrap_or(0);
+                (distance, land)
+            })
+            .ok_or(GameSetupError::NotEnoughStartPositions)?;
+        chosen.push(best.0);
+    }
+
+    Ok(chosen)
+}
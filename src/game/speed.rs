@@ -0,0 +1,155 @@
+use super::Game;
+use crate::{research::Technology, units::UnitType};
+
+/// The predefined speeds a game can be played at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GameSpeed {
+    /// Everything takes half as long.
+    Quick,
+    /// The turns and costs of the ruleset.
+    #[default]
+    Normal,
+    /// Everything takes half again as long.
+    Long,
+}
+
+impl GameSpeed {
+    /// The modifiers this speed applies.
+    #[must_use]
+    pub const fn modifiers(self) -> SpeedModifiers {
+        let percent = match self {
+            Self::Quick => 50,
+            Self::Normal => 100,
+            Self::Long => 150,
+        };
+
+        SpeedModifiers {
+            transform_percent: percent,
+            science_percent: percent,
+            production_percent: percent,
+        }
+    }
+}
+
+/// Percentages that scale the turns and costs of the ruleset, similar to
+/// FreeCiv's `sciencebox` and `shieldbox` settings. All rounding goes through
+/// this struct, so that every part of the game scales the same way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpeedModifiers {
+    /// Scales the turns workers need for terrain transforms.
+    pub transform_percent: u16,
+    /// Scales the bulbs needed to research technologies.
+    pub science_percent: u16,
+    /// Scales the shields needed to build units.
+    pub production_percent: u16,
+}
+
+impl Default for SpeedModifiers {
+    fn default() -> Self {
+        GameSpeed::Normal.modifiers()
+    }
+}
+
+impl SpeedModifiers {
+    /// The turns a transform takes that takes `turns` at normal speed. Every
+    /// transform takes at least one turn.
+    #[must_use]
+    pub fn transform_turns(&self, turns: u8) -> u8 {
+        let scaled = scale(u32::from(turns), self.transform_percent).max(1);
+
+        u8::try_from(scaled).unwrap_or(u8::MAX)
+    }
+
+    /// The bulbs needed for a technology that needs `bulbs` at normal speed.
+    #[must_use]
+    pub fn bulbs(&self, bulbs: u16) -> u32 {
+        scale(u32::from(bulbs), self.science_percent)
+    }
+
+    /// The shields needed for something that costs `shields` at normal speed.
+    #[must_use]
+    pub fn production_cost(&self, shields: u16) -> u32 {
+        scale(u32::from(shields), self.production_percent)
+    }
+}
+
+/// Scales a value by a percentage, rounding to the nearest integer.
+fn scale(value: u32, percent: u16) -> u32 {
+    (value * u32::from(percent) + 50) / 100
+}
+
+impl Game {
+    /// The speed modifiers of this game.
+    #[must_use]
+    pub const fn speed(&self) -> &SpeedModifiers {
+        &self.speed
+    }
+
+    /// The bulbs needed to research a technology in this game.
+    #[must_use]
+    pub fn research_cost(&self, technology: &Technology) -> u32 {
+        self.speed.bulbs(technology.bulbs())
+    }
+
+    /// The shields needed to build a unit in this game.
+    #[must_use]
+    pub fn production_cost(&self, kind: &UnitType) -> u32 {
+        self.speed.production_cost(kind.build_cost())
+    }
+}
+
+#[test]
+fn test_speed_modifiers() {
+    use super::GameSetup;
+    use crate::{
+        research::ALPHABET,
+        tiles::{Flags, Terrain, Transform, TransformResult},
+        units::WARRIORS,
+        world::{generate, Parameters},
+    };
+
+    let normal = GameSpeed::Normal.modifiers();
+    let quick = GameSpeed::Quick.modifiers();
+    let long = GameSpeed::Long.modifiers();
+    assert_eq!(normal, SpeedModifiers::default());
+    assert_eq!(normal.transform_turns(5), 5);
+    assert_eq!(quick.transform_turns(5), 3);
+    assert_eq!(quick.transform_turns(1), 1);
+    assert_eq!(long.transform_turns(24), 36);
+    assert_eq!(long.transform_turns(200), u8::MAX);
+    assert_eq!(quick.bulbs(30), 15);
+    assert_eq!(long.production_cost(10), 15);
+
+    let world = generate(Parameters {
+        width: 16,
+        height: 16,
+        ..Parameters::default()
+    })
+    .unwrap();
+    let mut tile = world.tile_at(0, 0).unwrap().clone();
+    tile.terrain = Terrain::Grassland;
+    tile.flags = Flags::empty();
+    assert!(matches!(
+        tile.start_transform(Transform::Road, &long),
+        TransformResult::Possible { turns: 3 }
+    ));
+    for _ in 0..2 {
+        tile.tick_transform();
+    }
+    assert!(!tile.flags.contains(Flags::HAS_ROAD));
+    tile.tick_transform();
+    assert!(tile.flags.contains(Flags::HAS_ROAD));
+
+    let game = Game::new(
+        world,
+        &GameSetup {
+            players: 1,
+            speed: quick,
+            ..GameSetup::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(game.speed(), &quick);
+    assert_eq!(game.research_cost(&ALPHABET), 15);
+    assert_eq!(game.production_cost(&WARRIORS), 5);
+}
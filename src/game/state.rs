@@ -0,0 +1,383 @@
+use std::collections::BTreeMap;
+
+use super::{
+    player_map::{CITY_VISION_RADIUS, VISION_RADIUS},
+    setup::{choose_start_positions, StartUnit},
+    GameSetup, GameSetupError, ObserverVision, Player, PlayerId, PlayerKind, PlayerMap,
+    SpeedModifiers,
+};
+use crate::{
+    cities::{City, CityId},
+    research::Technologies,
+    units::{Unit, UnitId, UnitType},
+    world::World,
+};
+
+/// A game in progress: the world, the players taking part, their units and
+/// their cities.
+///
+/// Games can be stored and restored with [`Savegame`](crate::save::Savegame).
+pub struct Game {
+    pub(crate) world: World,
+    pub(crate) players: Vec<Player>,
+    pub(crate) units: BTreeMap<UnitId, Unit>,
+    pub(crate) next_unit_id: u32,
+    pub(crate) cities: BTreeMap<CityId, City>,
+    pub(crate) next_city_id: u32,
+    pub(crate) turn: u32,
+    pub(crate) rng: fastrand::Rng,
+    pub(crate) speed: SpeedModifiers,
+}
+
+impl Clone for Game {
+    fn clone(&self) -> Self {
+        Self {
+            world: self.world.clone(),
+            players: self.players.clone(),
+            units: self.units.clone(),
+            next_unit_id: self.next_unit_id,
+            cities: self.cities.clone(),
+            next_city_id: self.next_city_id,
+            turn: self.turn,
+            // Cloning a `fastrand::Rng` derives a new generator, but the clone
+            // should continue with the same random numbers.
+            rng: fastrand::Rng::with_seed(self.rng.get_seed()),
+            speed: self.speed,
+        }
+    }
+}
+
+impl Game {
+    /// Creates a new game on a generated world. Every player gets a start
+    /// position and the starting units configured in the setup.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`GameSetupError`] if the setup is invalid or the world does
+    /// not have room for all players.
+    pub fn new(world: World, setup: &GameSetup) -> Result<Self, GameSetupError> {
+        setup.validate()?;
+        let start_units = setup.parse_start_units()?;
+        let start_positions = choose_start_positions(&world, setup.players)?;
+
+        let mut game = Self {
+            world,
+            players: Vec::with_capacity(usize::from(setup.players)),
+            units: BTreeMap::new(),
+            next_unit_id: 0,
+            cities: BTreeMap::new(),
+            next_city_id: 0,
+            turn: 1,
+            rng: fastrand::Rng::with_seed(setup.seed),
+            speed: setup.speed,
+        };
+
+        for (id, start_position) in (0..setup.players).zip(start_positions) {
+            let player = PlayerId(id);
+            game.players.push(Player {
+                id: player,
+                kind: PlayerKind::Participant,
+                start_position: Some(start_position),
+                technologies: Technologies::new(),
+                researching: None,
+                map: PlayerMap::unknown(game.world.width, game.world.height),
+            });
+
+            for &start_unit in &start_units {
+                game.place_start_unit(player, start_unit, start_position);
+            }
+        }
+
+        Ok(game)
+    }
+
+    /// Places a starting unit at the player's start position. Ferryboats are
+    /// placed on an adjacent water tile instead and skipped if there is none.
+    fn place_start_unit(&mut self, owner: PlayerId, start_unit: StartUnit, (x, y): (usize, usize)) {
+        let kind = start_unit.unit_type();
+        let position = if start_unit == StartUnit::Ferryboat {
+            let water = self
+                .world
+                .tiles_within(x, y, 1)
+                .find(|tile| tile.terrain.passable_for(kind.class()))
+                .map(|tile| tile.position());
+
+            match water {
+                Some(position) => position,
+                None => return,
+            }
+        } else {
+            (x, y)
+        };
+
+        self.spawn_unit(owner, kind, position);
+    }
+
+    /// Creates a new unit for a player on the given tile.
+    pub fn spawn_unit(
+        &mut self,
+        owner: PlayerId,
+        kind: &'static UnitType,
+        (x, y): (usize, usize),
+    ) -> UnitId {
+        let id = UnitId(self.next_unit_id);
+        self.next_unit_id += 1;
+        self.units.insert(id, Unit::new(id, owner, kind, x, y));
+        self.update_vision();
+
+        id
+    }
+
+    /// Adds an observer that does not take part in the game.
+    ///
+    /// Returns `None` if the maximum number of players is reached.
+    pub fn add_observer(&mut self, vision: ObserverVision) -> Option<PlayerId> {
+        let id = PlayerId(u8::try_from(self.players.len()).ok()?);
+        self.players.push(Player {
+            id,
+            kind: PlayerKind::Observer(vision),
+            start_position: None,
+            technologies: Technologies::new(),
+            researching: None,
+            map: PlayerMap::unknown(self.world.width, self.world.height),
+        });
+
+        Some(id)
+    }
+
+    /// Recomputes which tiles each participant currently sees from the
+    /// positions of their units and cities, and records that they were seen in
+    /// the current turn.
+    pub(crate) fn update_vision(&mut self) {
+        for player in &mut self.players {
+            player.map.clear_visible();
+        }
+
+        let units = self
+            .units
+            .values()
+            .map(|unit| (unit.owner, unit.position(), VISION_RADIUS));
+        let cities = self
+            .cities
+            .values()
+            .map(|city| (city.owner, city.position(), CITY_VISION_RADIUS));
+
+        for (owner, (x, y), radius) in units.chain(cities) {
+            let Some(player) = self.players.iter_mut().find(|p| p.id == owner) else {
+                continue;
+            };
+
+            for tile in self.world.tiles_within(x, y, radius) {
+                let (x, y) = tile.position();
+                player.map.see(x, y, self.turn);
+            }
+        }
+    }
+
+    /// The part of the world known to a player. Observers see the whole world
+    /// or the combined knowledge of the players they observe.
+    #[must_use]
+    pub fn player_map(&self, id: PlayerId) -> Option<PlayerMap> {
+        let player = self.player(id)?;
+
+        Some(match &player.kind {
+            PlayerKind::Participant => player.map.clone(),
+            PlayerKind::Observer(ObserverVision::Full) => {
+                PlayerMap::full(self.world.width, self.world.height, self.turn)
+            }
+            PlayerKind::Observer(ObserverVision::Players(observed)) => {
+                let mut map = PlayerMap::unknown(self.world.width, self.world.height);
+                for other in self.players.iter().filter(|p| observed.contains(&p.id)) {
+                    map.merge(&other.map);
+                }
+
+                map
+            }
+        })
+    }
+
+    #[must_use]
+    pub const fn world(&self) -> &World {
+        &self.world
+    }
+
+    #[must_use]
+    pub fn players(&self) -> &[Player] {
+        &self.players
+    }
+
+    #[must_use]
+    pub fn player(&self, id: PlayerId) -> Option<&Player> {
+        self.players.iter().find(|player| player.id == id)
+    }
+
+    #[must_use]
+    pub fn unit(&self, id: UnitId) -> Option<&Unit> {
+        self.units.get(&id)
+    }
+
+    /// Iterates over all units of all players.
+    pub fn units(&self) -> impl Iterator<Item = &Unit> {
+        self.units.values()
+    }
+
+    /// Iterates over all units on the tile at `x`, `y`.
+    pub fn units_at(&self, x: usize, y: usize) -> impl Iterator<Item = &Unit> {
+        self.units
+            .values()
+            .filter(move |unit| unit.position() == (x, y))
+    }
+
+    #[must_use]
+    pub fn city(&self, id: CityId) -> Option<&City> {
+        self.cities.get(&id)
+    }
+
+    /// Iterates over all cities of all players.
+    pub fn cities(&self) -> impl Iterator<Item = &City> {
+        self.cities.values()
+    }
+
+    /// The city on the tile at `x`, `y`, if there is one.
+    #[must_use]
+    pub fn city_at(&self, x: usize, y: usize) -> Option<&City> {
+        self.cities.values().find(|city| city.position() == (x, y))
+    }
+
+    /// The current turn, starting at 1.
+    #[must_use]
+    pub const fn turn(&self) -> u32 {
+        self.turn
+    }
+}
+
+#[test]
+fn test_start_units_placement() {
+    use crate::world::{generate, Parameters};
+
+    let world = generate(Parameters {
+        base_frequency: 0.05,
+        water_percentage: 0.5,
+        ..Parameters::default()
+    })
+    .unwrap();
+    let setup = GameSetup {
+        players: 3,
+        start_units: String::from("ccwwxks"),
+        ..GameSetup::default()
+    };
+    let game = Game::new(world, &setup).unwrap();
+
+    assert_eq!(game.players().len(), 3);
+    for player in game.players() {
+        let (x, y) = player.start_position().unwrap();
+        assert!(!game.world().tile_at(x, y).unwrap().terrain.is_water());
+        assert_eq!(game.units_at(x, y).count(), 7);
+        assert!(game.units_at(x, y).all(|unit| unit.owner() == player.id()));
+    }
+
+    let invalid = GameSetup {
+        start_units: String::from("cq"),
+        ..GameSetup::default()
+    };
+    let world = generate(Parameters::default()).unwrap();
+    assert_eq!(
+        Game::new(world.clone(), &invalid).err(),
+        Some(GameSetupError::UnknownStartUnit('q'))
+    );
+
+    let nobody = GameSetup {
+        players: 0,
+        ..GameSetup::default()
+    };
+    assert_eq!(nobody.validate(), Err(GameSetupError::NoPlayers));
+    assert_eq!(
+        Game::new(world, &nobody).err(),
+        Some(GameSetupError::NoPlayers)
+    );
+}
+
+#[test]
+fn test_observer_vision() {
+    use crate::world::{generate, Parameters};
+
+    let world = generate(Parameters {
+        base_frequency: 0.05,
+        water_percentage: 0.5,
+        ..Parameters::default()
+    })
+    .unwrap();
+    let mut game = Game::new(world, &GameSetup::default()).unwrap();
+
+    let observer = game.add_observer(ObserverVision::Full).unwrap();
+    let full = game.player_map(observer).unwrap();
+    assert!(game.player(observer).unwrap().is_observer());
+    assert!(full.is_visible(0, 0));
+
+    let first = game.players()[0].id();
+    let (x, y) = game.players()[0].start_position().unwrap();
+    let spectator = game
+        .add_observer(ObserverVision::Players(vec![first]))
+        .unwrap();
+    let first_map = game.player_map(first).unwrap();
+    let shared = game.player_map(spectator).unwrap();
+    assert!(shared.is_visible(x, y));
+    for y in 0..game.world().height() {
+        for x in 0..game.world().width() {
+            assert_eq!(first_map.is_known(x, y), shared.is_known(x, y));
+        }
+    }
+}
+
+#[test]
+fn test_exploration_timestamps() {
+    use super::Action;
+    use crate::{
+        tiles::{Flags, Terrain},
+        units::WARRIORS,
+        world::{generate, Direction, Parameters},
+    };
+
+    let mut world = generate(Parameters {
+        width: 8,
+        height: 8,
+        wrapping_x: false,
+        ..Parameters::default()
+    })
+    .unwrap();
+    for y in 0..8 {
+        for x in 0..8 {
+            let mut tile = world.tile_at_mut(x, y).unwrap();
+            tile.terrain = Terrain::Grassland;
+            tile.flags = Flags::empty();
+        }
+    }
+
+    let mut game = Game::new(
+        world,
+        &GameSetup {
+            players: 1,
+            start_units: String::new(),
+            ..GameSetup::default()
+        },
+    )
+    .unwrap();
+    let player = game.players()[0].id();
+    let warriors = game.spawn_unit(player, &WARRIORS, (1, 4));
+    game.end_turn();
+
+    let east = Action::Move {
+        direction: Direction::East,
+    };
+    assert!(game.perform_action(warriors, east).is_ok());
+    game.end_turn();
+
+    let map = game.player_map(player).unwrap();
+    assert_eq!(game.turn(), 3);
+    assert_eq!(map.last_seen(0, 4), Some(2));
+    assert_eq!(map.intel_age(0, 4, game.turn()), Some(1));
+    assert_eq!(map.last_seen(3, 4), Some(3));
+    assert_eq!(map.intel_age(3, 4, game.turn()), Some(0));
+    assert_eq!(map.last_seen(6, 4), None);
+    assert!(map.is_known(0, 4) && !map.is_visible(0, 4));
+}
@@ -0,0 +1,32 @@
+#![feature(once_cell)]
+#![deny(
+    clippy::pedantic,
+    clippy::missing_const_for_fn,
+    rustdoc::broken_intra_doc_links
+)]
+//! A reimplementation of a slightly simplified version of
+//! [FreeCiv](https://www.freeciv.org/).
+//!
+//! Most programs only need the [`prelude`]:
+//!
+//! ```no_run
+//! use freeciv_rs::prelude::*;
+//!
+//! let world = generate(Parameters::default()).unwrap();
+//! let tile = world.tile_at(0, 0).unwrap();
+//! println!("{:?}", tile.terrain());
+//! ```
+//!
+//! `tests/public-api.txt` is a snapshot of every public item and its
+//! signature, generated from the rustdoc JSON by `tests/public_api.rs`, so
+//! changes to the public API show up in review. The examples in the
+//! documentation are compiled and run as doctests.
+
+pub mod cities;
+pub mod game;
+pub mod prelude;
+pub mod research;
+pub mod save;
+pub mod tiles;
+pub mod units;
+pub mod world;
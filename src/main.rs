@@ -1,22 +1,13 @@
-#![feature(once_cell)]
 #![deny(
     clippy::pedantic,
     clippy::missing_const_for_fn,
     rustdoc::broken_intra_doc_links
 )]
 
-use image::ImageFormat;
-use world::{generate, Parameters};
+use std::process::ExitCode;
 
-pub mod research;
-pub mod tiles;
-pub mod world;
+mod cli;
 
-fn main() {
-    let params = Parameters::default();
-    let world = generate(params);
-    let rendered = world.render();
-    rendered
-        .save_with_format("map.png", ImageFormat::Png)
-        .unwrap();
+fn main() -> ExitCode {
+    cli::main(std::env::args().skip(1))
 }
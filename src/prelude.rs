@@ -0,0 +1,15 @@
+//! The most commonly used types, for glob imports:
+//!
+//! ```
+//! use freeciv_rs::prelude::*;
+//! ```
+
+pub use crate::{
+    cities::{City, CityId},
+    game::{Action, ActionError, Game, GameSetup, GameSpeed, Player, PlayerId, PlayerMap},
+    research::{Technologies, Technology},
+    save::Savegame,
+    tiles::{Flags, Special, Terrain, Tile, Transform, TransformOutcome, TransformResult},
+    units::{Unit, UnitId, UnitType},
+    world::{generate, Direction, MapScripts, Parameters, Renderer, World},
+};
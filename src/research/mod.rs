@@ -1,3 +1,14 @@
+//! The technology tree and the technologies known to a player.
+//!
+//! ```
+//! use freeciv_rs::research::{Technologies, ALPHABET, WRITING};
+//!
+//! let mut known = Technologies::new();
+//! assert!(!known.can_research(&WRITING));
+//! known.set_researched(&ALPHABET);
+//! assert!(known.can_research(&WRITING));
+//! ```
+
 mod technologies;
 mod technology;
 
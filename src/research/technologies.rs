@@ -39,6 +39,7 @@ fn get_technology_by_idx(idx: usize) -> &'static Technology {
 
 /// A struct that holds the information on which technologies a player has
 /// acquired so far.
+#[derive(Clone)]
 pub struct Technologies(FixedBitSet);
 
 impl Technologies {
@@ -59,6 +60,17 @@ impl Technologies {
         self.0.contains(get_technology_idx(technology))
     }
 
+    /// Whether the technology has not been researched yet, but all of its
+    /// requirements have.
+    #[must_use]
+    pub fn can_research(&self, technology: &'static Technology) -> bool {
+        !self.has_researched(technology)
+            && technology
+                .requirements()
+                .iter()
+                .all(|&requirement| self.has_researched(requirement))
+    }
+
     pub fn set_researched(&mut self, technology: &'static Technology) {
         self.0.set(get_technology_idx(technology), true);
     }
@@ -77,12 +89,16 @@ impl Default for Technologies {
 
 #[test]
 fn test_technologies() {
-    use super::ALPHABET;
+    use super::{ALPHABET, MAP_MAKING};
 
     let mut techs = Technologies::new();
     assert!(!techs.has_researched(&ALPHABET));
+    assert!(techs.can_research(&ALPHABET));
+    assert!(!techs.can_research(&MAP_MAKING));
     techs.set_researched(&ALPHABET);
     assert!(techs.has_researched(&ALPHABET));
+    assert!(!techs.can_research(&ALPHABET));
+    assert!(techs.can_research(&MAP_MAKING));
     let blocks = techs.as_blocks();
 
     let other_techs = Technologies::from_blocks(blocks.iter().copied());
@@ -32,6 +32,24 @@ impl Hash for Technology {
 }
 
 impl Technology {
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The bulbs needed to research this technology at normal game speed, see
+    /// [`Game::research_cost`](crate::game::Game::research_cost).
+    #[must_use]
+    pub const fn bulbs(&self) -> u16 {
+        self.bulbs
+    }
+
+    /// The technologies that have to be researched before this one.
+    #[must_use]
+    pub const fn requirements(&self) -> &'static [&'static Technology] {
+        self.requirements
+    }
+
     /// Insert all technologies required to research this technology and their
     /// requirements recursively into the set.
     fn techs_required_recursive(&self, set: &mut HashSet<&'static Self>) {
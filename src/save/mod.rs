@@ -0,0 +1,5 @@
+mod savegame;
+mod secfile;
+
+pub use savegame::Savegame;
+pub use secfile::LoadError;
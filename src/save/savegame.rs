@@ -0,0 +1,815 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    fs::File,
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    path::Path,
+};
+
+use super::secfile::{numbered, LoadError, Section, SectionFile};
+use crate::{
+    cities::{City, CityId},
+    game::{Game, ObserverVision, Player, PlayerId, PlayerKind, PlayerMap, SpeedModifiers},
+    research::{Technologies, Technology, ALL_TECHNOLOGIES},
+    tiles::{Flags, Special, TagValue, Terrain, Tile, Transform, TransformStatus},
+    units::{Activity, Unit, UnitId, ALL_UNIT_TYPES},
+    world::World,
+};
+
+/// Version of the savegame format written by [`Savegame::write`].
+const SAVEGAME_VERSION: u32 = 2;
+
+/// Activities of units, as stored in the unit entries.
+const ACTIVITIES: [(Activity, &str); 4] = [
+    (Activity::Idle, "idle"),
+    (Activity::Fortifying, "fortifying"),
+    (Activity::Fortified, "fortified"),
+    (Activity::BuildingRoad, "road"),
+];
+
+/// Transforms of terrain work in progress, as stored in the transform entries.
+const TRANSFORMS: [(Transform, &str); 4] = [
+    (Transform::Irrigation, "irrigation"),
+    (Transform::Mining, "mining"),
+    (Transform::Road, "road"),
+    (Transform::Transforming, "transforming"),
+];
+
+/// A scenario or a game in progress, as stored in savegame and scenario files.
+///
+/// The files use the section file format of FreeCiv's savegames, with one
+/// row of the map per entry and sections for terrain work in progress and
+/// tile tags. Savegames store the whole state of the game, so that a loaded
+/// game continues exactly like the saved one would have, including the
+/// outcome of combat.
+pub enum Savegame {
+    /// A world without players to start new games on.
+    Scenario(World),
+    /// A game with its players, units and cities.
+    Game(Game),
+}
+
+impl Savegame {
+    /// Creates a scenario of a world without any players.
+    #[must_use]
+    pub const fn scenario(world: World) -> Self {
+        Self::Scenario(world)
+    }
+
+    /// Captures the whole state of a game.
+    #[must_use]
+    pub fn from_game(game: &Game) -> Self {
+        Self::Game(game.clone())
+    }
+
+    /// Whether this is a scenario without players.
+    #[must_use]
+    pub const fn is_scenario(&self) -> bool {
+        matches!(self, Self::Scenario(_))
+    }
+
+    #[must_use]
+    pub const fn world(&self) -> &World {
+        match self {
+            Self::Scenario(world) => world,
+            Self::Game(game) => game.world(),
+        }
+    }
+
+    /// The current turn of the game. Scenarios start at turn 1.
+    #[must_use]
+    pub const fn turn(&self) -> u32 {
+        match self {
+            Self::Scenario(_) => 1,
+            Self::Game(game) => game.turn(),
+        }
+    }
+
+    /// The stored game, or `None` for scenarios.
+    #[must_use]
+    pub const fn game(&self) -> Option<&Game> {
+        match self {
+            Self::Scenario(_) => None,
+            Self::Game(game) => Some(game),
+        }
+    }
+
+    /// Takes the stored game to continue playing it, or `None` for scenarios.
+    #[must_use]
+    pub fn into_game(self) -> Option<Game> {
+        match self {
+            Self::Scenario(_) => None,
+            Self::Game(game) => Some(game),
+        }
+    }
+
+    /// Reads a savegame or scenario file.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LoadError`] if the file cannot be read or is invalid.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, LoadError> {
+        Self::read(BufReader::new(File::open(path)?))
+    }
+
+    /// Writes the savegame to a file, replacing it if it exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.write(BufWriter::new(File::create(path)?))
+    }
+
+    /// Reads a savegame or scenario.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LoadError`] if reading fails or the data is invalid.
+    pub fn read<R: BufRead>(reader: R) -> Result<Self, LoadError> {
+        let file = SectionFile::read(reader)?;
+
+        let savefile = file.require_section("savefile")?;
+        if savefile.int::<u32>("version")? != SAVEGAME_VERSION {
+            return Err(savefile.invalid("version"));
+        }
+
+        let mut world = read_world(file.require_section("map")?)?;
+        if let Some(section) = file.section("transforms") {
+            read_transforms(section, &mut world)?;
+        }
+        if let Some(section) = file.section("tags") {
+            read_tags(section, &mut world)?;
+        }
+
+        if file.section("scenario").is_some() {
+            return Ok(Self::Scenario(world));
+        }
+
+        read_game(&file, world).map(Self::Game)
+    }
+
+    /// Writes the savegame.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    pub fn write<W: Write>(&self, writer: W) -> io::Result<()> {
+        let mut file = SectionFile::default();
+        let world = self.world();
+
+        file.add_section("savefile")
+            .set_int("version", SAVEGAME_VERSION);
+        if self.is_scenario() {
+            file.add_section("scenario").set_bool("is_scenario", true);
+        }
+        write_world(file.add_section("map"), world);
+        let tiles = || world.grid.iter().flatten();
+        if tiles().any(|tile| tile.transform_status != TransformStatus::NotTransforming) {
+            write_transforms(file.add_section("transforms"), world);
+        }
+        if tiles().any(|tile| !tile.tags.is_empty()) {
+            write_tags(file.add_section("tags"), world);
+        }
+
+        if let Self::Game(game) = self {
+            write_game(&mut file, game);
+        }
+
+        file.write(writer)
+    }
+}
+
+/// Writes everything of a game except for the world: the turn, the state of
+/// the random number generator, the speed, the players, the units and the
+/// cities.
+fn write_game(file: &mut SectionFile, game: &Game) {
+    let section = file.add_section("game");
+    section.set_int("turn", game.turn);
+    section.set_int("rng_seed", game.rng.get_seed());
+    section.set_int("transform_percent", game.speed.transform_percent);
+    section.set_int("science_percent", game.speed.science_percent);
+    section.set_int("production_percent", game.speed.production_percent);
+    section.set_int("next_unit_id", game.next_unit_id);
+    section.set_int("next_city_id", game.next_city_id);
+
+    file.add_section("players")
+        .set_int("count", game.players.len());
+    for (index, player) in game.players.iter().enumerate() {
+        write_player(
+            file.add_section(&numbered("player", index)),
+            player,
+            &game.world,
+        );
+    }
+
+    let section = file.add_section("units");
+    section.set_int("count", game.units.len());
+    for (index, unit) in game.units.values().enumerate() {
+        section.set_int(&numbered("id", index), unit.id.0);
+        section.set_int(&numbered("owner", index), unit.owner.0);
+        section.set_str(&numbered("type", index), unit.kind.name());
+        section.set_int(&numbered("x", index), unit.x);
+        section.set_int(&numbered("y", index), unit.y);
+        section.set_int(&numbered("moves", index), unit.moves_left);
+        section.set_int(&numbered("hp", index), unit.hitpoints);
+        section.set_str(&numbered("activity", index), activity_name(unit.activity));
+    }
+
+    let section = file.add_section("cities");
+    section.set_int("count", game.cities.len());
+    for (index, city) in game.cities.values().enumerate() {
+        section.set_int(&numbered("id", index), city.id.0);
+        section.set_int(&numbered("owner", index), city.owner.0);
+        section.set_int(&numbered("x", index), city.x);
+        section.set_int(&numbered("y", index), city.y);
+        section.set_int(&numbered("size", index), city.size);
+    }
+}
+
+fn read_game(file: &SectionFile, world: World) -> Result<Game, LoadError> {
+    let section = file.require_section("game")?;
+    let turn = section.int("turn")?;
+    let rng = fastrand::Rng::with_seed(section.int("rng_seed")?);
+    let speed = SpeedModifiers {
+        transform_percent: section.int("transform_percent")?,
+        science_percent: section.int("science_percent")?,
+        production_percent: section.int("production_percent")?,
+    };
+    let next_unit_id = section.int("next_unit_id")?;
+    let next_city_id = section.int("next_city_id")?;
+
+    let count = file.require_section("players")?.int("count")?;
+    let players: Vec<Player> = (0..count)
+        .map(|index| read_player(file.require_section(&numbered("player", index))?, &world))
+        .collect::<Result<_, _>>()?;
+    let owner = |section: &Section, key: &str| {
+        let owner = PlayerId(section.int(key)?);
+        match players.iter().find(|player| player.id == owner) {
+            Some(player) if !player.is_observer() => Ok(owner),
+            _ => Err(section.invalid(key)),
+        }
+    };
+    let position = |section: &Section, index: usize| {
+        let x_key = numbered("x", index);
+        let (x, y) = (section.int(&x_key)?, section.int(&numbered("y", index))?);
+        if x >= world.width || y >= world.height {
+            return Err(section.invalid(&x_key));
+        }
+        Ok((x, y))
+    };
+
+    let section = file.require_section("units")?;
+    let mut units = BTreeMap::new();
+    for index in 0..section.int("count")? {
+        let id = UnitId(section.int(&numbered("id", index))?);
+        let type_key = numbered("type", index);
+        let activity_key = numbered("activity", index);
+        let type_name = section.str(&type_key)?;
+        let (x, y) = position(section, index)?;
+        let unit = Unit {
+            id,
+            owner: owner(section, &numbered("owner", index))?,
+            kind: ALL_UNIT_TYPES
+                .iter()
+                .copied()
+                .find(|kind| kind.name() == type_name)
+                .ok_or_else(|| section.invalid(&type_key))?,
+            x,
+            y,
+            moves_left: section.int(&numbered("moves", index))?,
+            hitpoints: section.int(&numbered("hp", index))?,
+            activity: activity_from_name(section.str(&activity_key)?)
+                .ok_or_else(|| section.invalid(&activity_key))?,
+        };
+        if id.0 >= next_unit_id || units.insert(id, unit).is_some() {
+            return Err(section.invalid(&numbered("id", index)));
+        }
+    }
+
+    let section = file.require_section("cities")?;
+    let mut cities = BTreeMap::new();
+    for index in 0..section.int("count")? {
+        let id = CityId(section.int(&numbered("id", index))?);
+        let (x, y) = position(section, index)?;
+        let city = City {
+            id,
+            owner: owner(section, &numbered("owner", index))?,
+            x,
+            y,
+            size: section.int(&numbered("size", index))?,
+        };
+        if id.0 >= next_city_id || cities.insert(id, city).is_some() {
+            return Err(section.invalid(&numbered("id", index)));
+        }
+    }
+
+    Ok(Game {
+        world,
+        players,
+        units,
+        next_unit_id,
+        cities,
+        next_city_id,
+        turn,
+        rng,
+        speed,
+    })
+}
+
+/// Players store their kind, start position and research. Observers store
+/// `full_vision` or the comma-separated ids of the players they observe, and
+/// researched technologies are a comma-separated list of their names.
+fn write_player(section: &mut Section, player: &Player, world: &World) {
+    section.set_int("id", player.id.0);
+    match &player.kind {
+        PlayerKind::Participant => section.set_str("kind", "participant"),
+        PlayerKind::Observer(vision) => {
+            section.set_str("kind", "observer");
+            match vision {
+                ObserverVision::Full => section.set_bool("full_vision", true),
+                ObserverVision::Players(observed) => {
+                    section.set_bool("full_vision", false);
+                    let observed: Vec<String> =
+                        observed.iter().map(|id| id.0.to_string()).collect();
+                    section.set_str("observed", &observed.join(","));
+                }
+            }
+        }
+    }
+    if let Some((x, y)) = player.start_position {
+        section.set_int("start_x", x);
+        section.set_int("start_y", y);
+    }
+
+    let researched: Vec<&str> = ALL_TECHNOLOGIES
+        .iter()
+        .copied()
+        .filter(|&technology| player.technologies.has_researched(technology))
+        .map(Technology::name)
+        .collect();
+    section.set_str("researched", &researched.join(","));
+    if let Some(technology) = player.researching {
+        section.set_str("researching", technology.name());
+    }
+
+    write_player_map(section, &player.map, world);
+}
+
+fn read_player(section: &Section, world: &World) -> Result<Player, LoadError> {
+    let kind = match section.str("kind")? {
+        "participant" => PlayerKind::Participant,
+        "observer" if section.bool("full_vision")? => PlayerKind::Observer(ObserverVision::Full),
+        "observer" => {
+            let observed = section.str("observed")?;
+            let observed = observed
+                .split(',')
+                .filter(|id| !id.is_empty())
+                .map(|id| id.parse().map(PlayerId))
+                .collect::<Result<_, _>>()
+                .map_err(|_| section.invalid("observed"))?;
+            PlayerKind::Observer(ObserverVision::Players(observed))
+        }
+        _ => return Err(section.invalid("kind")),
+    };
+
+    let start_position = match section.int("start_x") {
+        Ok(x) => {
+            let y = section.int("start_y")?;
+            if x >= world.width || y >= world.height {
+                return Err(section.invalid("start_x"));
+            }
+            Some((x, y))
+        }
+        Err(LoadError::MissingEntry { .. }) => None,
+        Err(error) => return Err(error),
+    };
+
+    let mut technologies = Technologies::new();
+    for name in section.str("researched")?.split(',') {
+        if name.is_empty() {
+            continue;
+        }
+        let technology = technology_from_name(name).ok_or_else(|| section.invalid("researched"))?;
+        technologies.set_researched(technology);
+    }
+    let researching = match section.str("researching") {
+        Ok(name) => Some(technology_from_name(name).ok_or_else(|| section.invalid("researching"))?),
+        Err(LoadError::MissingEntry { .. }) => None,
+        Err(error) => return Err(error),
+    };
+
+    Ok(Player {
+        id: PlayerId(section.int("id")?),
+        kind,
+        start_position,
+        technologies,
+        researching,
+        map: read_player_map(section, world)?,
+    })
+}
+
+fn write_world(section: &mut Section, world: &World) {
+    section.set_int("width", world.width);
+    section.set_int("height", world.height);
+    section.set_bool("wrapping_x", world.wrapping_x);
+    section.set_bool("wrapping_y", world.wrapping_y);
+
+    for (y, row) in world.grid.iter().enumerate() {
+        let terrain: String = row.iter().map(|tile| tile.terrain.identifier()).collect();
+        section.set_str(&numbered("t", y), &terrain);
+    }
+    for (y, row) in world.grid.iter().enumerate() {
+        let specials: String = row.iter().map(|tile| tile.special.identifier()).collect();
+        section.set_str(&numbered("r", y), &specials);
+    }
+    for (y, row) in world.grid.iter().enumerate() {
+        let flags = row.iter().fold(String::new(), |mut flags, tile| {
+            // Writing to a `String` cannot fail.
+            let _ = write!(flags, "{:04x}", tile.flags.bits());
+            flags
+        });
+        section.set_str(&numbered("e", y), &flags);
+    }
+}
+
+fn read_world(section: &Section) -> Result<World, LoadError> {
+    let width: usize = section.int("width")?;
+    let height: usize = section.int("height")?;
+    if width == 0 || height == 0 {
+        return Err(section.invalid("width"));
+    }
+
+    let grid = (0..height)
+        .map(|y| {
+            let terrain_key = numbered("t", y);
+            let special_key = numbered("r", y);
+            let flags_key = numbered("e", y);
+
+            let terrain = section.str(&terrain_key)?;
+            let specials = section.str(&special_key)?;
+            let flags = section.str(&flags_key)?;
+            if terrain.chars().count() != width {
+                return Err(section.invalid(&terrain_key));
+            }
+            if specials.chars().count() != width {
+                return Err(section.invalid(&special_key));
+            }
+            if flags.len() != width * 4 || !flags.is_ascii() {
+                return Err(section.invalid(&flags_key));
+            }
+
+            terrain
+                .chars()
+                .zip(specials.chars())
+                .zip(flags.as_bytes().chunks(4))
+                .map(|((terrain, special), flags)| {
+                    let terrain = Terrain::from_identifier(terrain)
+                        .ok_or_else(|| section.invalid(&terrain_key))?;
+                    let special = Special::from_identifier(special)
+                        .ok_or_else(|| section.invalid(&special_key))?;
+                    let flags = std::str::from_utf8(flags)
+                        .ok()
+                        .and_then(|flags| u16::from_str_radix(flags, 16).ok())
+                        .ok_or_else(|| section.invalid(&flags_key))?;
+
+                    Ok(Tile::new(terrain, special, Flags::from_bits_retain(flags)))
+                })
+                .collect()
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(World {
+        width,
+        height,
+        grid,
+        wrapping_x: section.bool("wrapping_x")?,
+        wrapping_y: section.bool("wrapping_y")?,
+    })
+}
+
+/// Terrain work in progress is stored one per group of numbered entries: the
+/// position of the tile, the transform and the turns until it is done.
+fn write_transforms(section: &mut Section, world: &World) {
+    let mut count = 0;
+    for (y, row) in world.grid.iter().enumerate() {
+        for (x, tile) in row.iter().enumerate() {
+            let TransformStatus::Transforming {
+                transform,
+                turns_remaining,
+            } = tile.transform_status
+            else {
+                continue;
+            };
+
+            section.set_int(&numbered("x", count), x);
+            section.set_int(&numbered("y", count), y);
+            section.set_str(&numbered("transform", count), transform_name(transform));
+            section.set_int(&numbered("turns", count), turns_remaining);
+            count += 1;
+        }
+    }
+    section.set_int("count", count);
+}
+
+fn read_transforms(section: &Section, world: &mut World) -> Result<(), LoadError> {
+    let count: usize = section.int("count")?;
+
+    for index in 0..count {
+        let x_key = numbered("x", index);
+        let transform_key = numbered("transform", index);
+        let x = section.int(&x_key)?;
+        let y = section.int(&numbered("y", index))?;
+        let transform = transform_from_name(section.str(&transform_key)?)
+            .ok_or_else(|| section.invalid(&transform_key))?;
+        let turns_remaining = section.int(&numbered("turns", index))?;
+
+        let mut tile = world
+            .tile_at_mut(x, y)
+            .ok_or_else(|| section.invalid(&x_key))?;
+        tile.transform_status = TransformStatus::Transforming {
+            transform,
+            turns_remaining,
+        };
+    }
+
+    Ok(())
+}
+
+/// Tile tags are stored one per group of numbered entries: the position of the
+/// tile, the key and the value. Strings are quoted, integers and booleans are
+/// not.
+fn write_tags(section: &mut Section, world: &World) {
+    let mut count = 0;
+    for (y, row) in world.grid.iter().enumerate() {
+        for (x, tile) in row.iter().enumerate() {
+            for (key, value) in tile.tags.iter() {
+                section.set_int(&numbered("x", count), x);
+                section.set_int(&numbered("y", count), y);
+                section.set_str(&numbered("key", count), key);
+                let value_key = numbered("value", count);
+                match value {
+                    TagValue::Integer(value) => section.set_int(&value_key, value),
+                    TagValue::Boolean(value) => section.set_bool(&value_key, *value),
+                    TagValue::String(value) => section.set_str(&value_key, value),
+                }
+                count += 1;
+            }
+        }
+    }
+    section.set_int("count", count);
+}
+
+fn read_tags(section: &Section, world: &mut World) -> Result<(), LoadError> {
+    let count: usize = section.int("count")?;
+
+    for index in 0..count {
+        let x_key = numbered("x", index);
+        let value_key = numbered("value", index);
+        let x = section.int(&x_key)?;
+        let y = section.int(&numbered("y", index))?;
+        let key = section.str(&numbered("key", index))?;
+        let value = if section.is_str(&value_key)? {
+            TagValue::String(section.str(&value_key)?.to_owned())
+        } else if let Ok(value) = section.bool(&value_key) {
+            TagValue::Boolean(value)
+        } else {
+            TagValue::Integer(section.int(&value_key)?)
+        };
+
+        let mut tile = world
+            .tile_at_mut(x, y)
+            .ok_or_else(|| section.invalid(&x_key))?;
+        tile.tags.set(key, value);
+    }
+
+    Ok(())
+}
+
+/// Player maps store one character per tile, `0` for unknown, `1` for known
+/// and `2` for visible tiles, and the turn each tile was last seen in as a
+/// comma-separated list with empty values for unknown tiles.
+fn write_player_map(section: &mut Section, map: &PlayerMap, world: &World) {
+    for y in 0..world.height {
+        let known: String = (0..world.width)
+            .map(|x| {
+                if map.is_visible(x, y) {
+                    '2'
+                } else if map.is_known(x, y) {
+                    '1'
+                } else {
+                    '0'
+                }
+            })
+            .collect();
+        section.set_str(&numbered("k", y), &known);
+
+        let last_seen: Vec<String> = (0..world.width)
+            .map(|x| {
+                map.last_seen(x, y)
+                    .map(|turn| turn.to_string())
+                    .unwrap_or_default()
+            })
+            .collect();
+        section.set_str(&numbered("l", y), &last_seen.join(","));
+    }
+}
+
+fn read_player_map(section: &Section, world: &World) -> Result<PlayerMap, LoadError> {
+    let mut map = PlayerMap::unknown(world.width, world.height);
+
+    for y in 0..world.height {
+        let known_key = numbered("k", y);
+        let last_seen_key = numbered("l", y);
+        let known = section.str(&known_key)?;
+        let last_seen = section.str(&last_seen_key)?;
+
+        if known.chars().count() != world.width {
+            return Err(section.invalid(&known_key));
+        }
+        let last_seen: Vec<&str> = last_seen.split(',').collect();
+        if last_seen.len() != world.width {
+            return Err(section.invalid(&last_seen_key));
+        }
+
+        for (x, (state, last_seen)) in known.chars().zip(last_seen).enumerate() {
+            if state == '0' {
+                continue;
+            }
+
+            let turn = last_seen
+                .parse()
+                .map_err(|_| section.invalid(&last_seen_key))?;
+            match state {
+                '1' => map.remember(x, y, turn),
+                '2' => map.see(x, y, turn),
+                _ => return Err(section.invalid(&known_key)),
+            }
+        }
+    }
+
+    Ok(map)
+}
+
+fn activity_name(activity: Activity) -> &'static str {
+    ACTIVITIES
+        .iter()
+        .find(|&&(a, _)| a == activity)
+        .map_or("idle", |&(_, name)| name)
+}
+
+fn activity_from_name(name: &str) -> Option<Activity> {
+    ACTIVITIES
+        .iter()
+        .find(|&&(_, n)| n == name)
+        .map(|&(activity, _)| activity)
+}
+
+fn transform_name(transform: Transform) -> &'static str {
+    TRANSFORMS
+        .iter()
+        .find(|&&(t, _)| t == transform)
+        .map_or("road", |&(_, name)| name)
+}
+
+fn transform_from_name(name: &str) -> Option<Transform> {
+    TRANSFORMS
+        .iter()
+        .find(|&&(_, n)| n == name)
+        .map(|&(transform, _)| transform)
+}
+
+fn technology_from_name(name: &str) -> Option<&'static Technology> {
+    ALL_TECHNOLOGIES
+        .iter()
+        .copied()
+        .find(|technology| technology.name() == name)
+}
+
+#[test]
+fn test_savegame_round_trip() {
+    use crate::{
+        game::{Action, GameSetup, GameSpeed},
+        research::{ALPHABET, BRONZE_WORKING},
+        units::{SETTLERS, WORKERS},
+        world::{generate, Parameters},
+    };
+
+    let params = || Parameters {
+        width: 24,
+        height: 16,
+        base_frequency: 0.1,
+        water_percentage: 0.4,
+        ..Parameters::default()
+    };
+    let write = |savegame: &Savegame| {
+        let mut buffer = Vec::new();
+        savegame.write(&mut buffer).unwrap();
+        buffer
+    };
+
+    let mut world = generate(params()).unwrap();
+    world.tile_at_mut(1, 2).unwrap().flags = Flags::HAS_RIVER | Flags::HAS_HUT;
+    world.tile_at_mut(3, 4).unwrap().special = Special::Whales;
+    let mut tile = world.tile_at_mut(5, 6).unwrap();
+    tile.tags_mut().set("editor.note", "a \"quoted\" note");
+    tile.tags_mut().set("volcano.active", true);
+    tile.tags_mut().set("volcano.eruptions", -3);
+    let tags = tile.tags().clone();
+    let fingerprint = world.fingerprint();
+
+    let setup = GameSetup {
+        seed: 5,
+        speed: GameSpeed::Long.modifiers(),
+        ..GameSetup::default()
+    };
+    let mut game = Game::new(world, &setup).unwrap();
+    let first = game.players()[0].id();
+    let second = game.players()[1].id();
+    game.players[0].technologies.set_researched(&BRONZE_WORKING);
+    game.set_research(first, &ALPHABET).unwrap();
+    let spectator = game
+        .add_observer(ObserverVision::Players(vec![second]))
+        .unwrap();
+    game.add_observer(ObserverVision::Full).unwrap();
+    let unit = |game: &Game, kind| {
+        game.units()
+            .find(|unit| unit.owner() == first && unit.kind() == kind)
+            .map(Unit::id)
+            .unwrap()
+    };
+    let settlers = unit(&game, &SETTLERS);
+    let workers = unit(&game, &WORKERS);
+    game.perform_action(settlers, Action::FoundCity).unwrap();
+    game.perform_action(workers, Action::BuildRoad).unwrap();
+    game.end_turn();
+
+    let savegame = Savegame::from_game(&game);
+    let buffer = write(&savegame);
+    let loaded = Savegame::read(buffer.as_slice()).unwrap();
+    assert_eq!(write(&loaded), buffer);
+
+    assert!(!loaded.is_scenario());
+    assert_eq!(loaded.world().fingerprint(), fingerprint);
+    assert_eq!(loaded.world().tile_at(5, 6).unwrap().tags(), &tags);
+    assert!(loaded.world().tile_at(6, 5).unwrap().tags().is_empty());
+    assert_eq!(loaded.turn(), game.turn());
+
+    let mut loaded = loaded.into_game().unwrap();
+    assert_eq!(loaded.speed(), &GameSpeed::Long.modifiers());
+    assert_eq!(loaded.players().len(), 4);
+    let player = loaded.player(first).unwrap();
+    assert!(player.technologies().has_researched(&BRONZE_WORKING));
+    assert_eq!(player.researching(), Some(&ALPHABET));
+    assert_eq!(
+        loaded.player(spectator).unwrap().kind(),
+        &PlayerKind::Observer(ObserverVision::Players(vec![second]))
+    );
+    assert_eq!(loaded.units().count(), game.units().count());
+    assert_eq!(
+        loaded.unit(workers).unwrap().activity(),
+        Activity::BuildingRoad
+    );
+    let (x, y) = loaded.unit(workers).unwrap().position();
+    assert_eq!(
+        loaded.world().tile_at(x, y).unwrap().transform_status,
+        game.world().tile_at(x, y).unwrap().transform_status
+    );
+    assert_eq!(loaded.cities().count(), 1);
+    for y in 0..16 {
+        for x in 0..24 {
+            let original = game.player_map(spectator).unwrap();
+            let map = loaded.player_map(spectator).unwrap();
+            assert_eq!(map.is_known(x, y), original.is_known(x, y));
+            assert_eq!(map.last_seen(x, y), original.last_seen(x, y));
+        }
+    }
+
+    game.end_turn();
+    loaded.end_turn();
+    assert_eq!(
+        write(&Savegame::from_game(&loaded)),
+        write(&Savegame::from_game(&game))
+    );
+
+    let scenario = Savegame::scenario(generate(params()).unwrap());
+    let buffer = write(&scenario);
+    let loaded = Savegame::read(buffer.as_slice()).unwrap();
+    assert!(loaded.is_scenario());
+    assert!(loaded.into_game().is_none());
+    assert_eq!(
+        Savegame::read(buffer.as_slice())
+            .unwrap()
+            .world()
+            .fingerprint(),
+        scenario.world().fingerprint()
+    );
+
+    let truncated = String::from_utf8(buffer)
+        .unwrap()
+        .replace("t0003=", "x0003=");
+    assert!(matches!(
+        Savegame::read(truncated.as_bytes()),
+        Err(LoadError::MissingEntry { .. })
+    ));
+}
@@ -0,0 +1,257 @@
+use std::{
+    fmt,
+    io::{self, BufRead, Write},
+    str::FromStr,
+};
+
+/// A section file in the format of FreeCiv's savegames and scenarios:
+/// `[section]` headers followed by `key=value` entries. Values are integers,
+/// `TRUE`/`FALSE` or quoted strings. Lines starting with `;` or `#` are
+/// comments.
+#[derive(Debug, Default)]
+pub(crate) struct SectionFile {
+    sections: Vec<Section>,
+}
+
+#[derive(Debug)]
+pub(crate) struct Section {
+    name: String,
+    entries: Vec<Entry>,
+}
+
+#[derive(Debug)]
+struct Entry {
+    key: String,
+    /// The value, unquoted and unescaped.
+    value: String,
+    quoted: bool,
+}
+
+/// The reason why a section file could not be read.
+#[derive(Debug)]
+pub enum LoadError {
+    Io(io::Error),
+    /// A line could not be parsed.
+    Syntax {
+        line: usize,
+    },
+    MissingSection(String),
+    MissingEntry {
+        section: String,
+        key: String,
+    },
+    /// An entry exists, but its value is invalid.
+    InvalidEntry {
+        section: String,
+        key: String,
+    },
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => error.fmt(f),
+            Self::Syntax { line } => write!(f, "syntax error on line {line}"),
+            Self::MissingSection(section) => write!(f, "missing section [{section}]"),
+            Self::MissingEntry { section, key } => write!(f, "missing entry {section}.{key}"),
+            Self::InvalidEntry { section, key } => write!(f, "invalid entry {section}.{key}"),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for LoadError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl SectionFile {
+    pub(crate) fn read<R: BufRead>(reader: R) -> Result<Self, LoadError> {
+        let mut file = Self::default();
+
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            let line = line.trim();
+            let syntax_error = || LoadError::Syntax { line: index + 1 };
+
+            if line.is_empty() || line.starts_with(';') || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                file.add_section(name);
+                continue;
+            }
+
+            let (key, value) = line.split_once('=').ok_or_else(syntax_error)?;
+            let value = value.trim();
+            let quoted = value.starts_with('"');
+            let value = parse_value(value).ok_or_else(syntax_error)?;
+            file.sections
+                .last_mut()
+                .ok_or_else(syntax_error)?
+                .entries
+                .push(Entry {
+                    key: key.trim().to_owned(),
+                    value,
+                    quoted,
+                });
+        }
+
+        Ok(file)
+    }
+
+    pub(crate) fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        for section in &self.sections {
+            writeln!(writer, "[{}]", section.name)?;
+            for entry in &section.entries {
+                if entry.quoted {
+                    writeln!(writer, "{}={}", entry.key, quote(&entry.value))?;
+                } else {
+                    writeln!(writer, "{}={}", entry.key, entry.value)?;
+                }
+            }
+            writeln!(writer)?;
+        }
+
+        writer.flush()
+    }
+
+    pub(crate) fn add_section(&mut self, name: &str) -> &mut Section {
+        self.sections.push(Section {
+            name: name.to_owned(),
+            entries: Vec::new(),
+        });
+
+        // SAFETY: A section was just pushed.
+        unsafe { self.sections.last_mut().unwrap_unchecked() }
+    }
+
+    pub(crate) fn section(&self, name: &str) -> Option<&Section> {
+        self.sections.iter().find(|section| section.name == name)
+    }
+
+    pub(crate) fn require_section(&self, name: &str) -> Result<&Section, LoadError> {
+        self.section(name)
+            .ok_or_else(|| LoadError::MissingSection(name.to_owned()))
+    }
+}
+
+impl Section {
+    fn push(&mut self, key: &str, value: String, quoted: bool) {
+        self.entries.push(Entry {
+            key: key.to_owned(),
+            value,
+            quoted,
+        });
+    }
+
+    pub(crate) fn set_int(&mut self, key: &str, value: impl fmt::Display) {
+        self.push(key, value.to_string(), false);
+    }
+
+    pub(crate) fn set_bool(&mut self, key: &str, value: bool) {
+        let value = if value { "TRUE" } else { "FALSE" };
+        self.push(key, value.to_owned(), false);
+    }
+
+    pub(crate) fn set_str(&mut self, key: &str, value: &str) {
+        self.push(key, value.to_owned(), true);
+    }
+
+    fn entry(&self, key: &str) -> Result<&Entry, LoadError> {
+        self.entries
+            .iter()
+            .find(|entry| entry.key == key)
+            .ok_or_else(|| LoadError::MissingEntry {
+                section: self.name.clone(),
+                key: key.to_owned(),
+            })
+    }
+
+    fn raw(&self, key: &str) -> Result<&str, LoadError> {
+        Ok(&self.entry(key)?.value)
+    }
+
+    /// Whether the value of an entry is a quoted string.
+    pub(crate) fn is_str(&self, key: &str) -> Result<bool, LoadError> {
+        Ok(self.entry(key)?.quoted)
+    }
+
+    pub(crate) fn invalid(&self, key: &str) -> LoadError {
+        LoadError::InvalidEntry {
+            section: self.name.clone(),
+            key: key.to_owned(),
+        }
+    }
+
+    pub(crate) fn int<T: FromStr>(&self, key: &str) -> Result<T, LoadError> {
+        self.raw(key)?.parse().map_err(|_| self.invalid(key))
+    }
+
+    pub(crate) fn bool(&self, key: &str) -> Result<bool, LoadError> {
+        match self.raw(key)? {
+            "TRUE" => Ok(true),
+            "FALSE" => Ok(false),
+            _ => Err(self.invalid(key)),
+        }
+    }
+
+    pub(crate) fn str(&self, key: &str) -> Result<&str, LoadError> {
+        self.raw(key)
+    }
+}
+
+/// Quotes a string value, escaping quotes, backslashes and newlines.
+fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+
+    quoted
+}
+
+/// Parses a value, unquoting and unescaping strings. Returns `None` for
+/// unterminated strings.
+fn parse_value(value: &str) -> Option<String> {
+    let Some(quoted) = value.strip_prefix('"') else {
+        return Some(value.to_owned());
+    };
+
+    let mut unquoted = String::with_capacity(quoted.len());
+    let mut chars = quoted.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => return chars.as_str().trim().is_empty().then_some(unquoted),
+            '\\' => match chars.next()? {
+                'n' => unquoted.push('\n'),
+                c => unquoted.push(c),
+            },
+            c => unquoted.push(c),
+        }
+    }
+
+    None
+}
+
+/// Formats a number with leading zeros for numbered keys such as `t0042`.
+pub(crate) fn numbered(prefix: &str, number: usize) -> String {
+    format!("{prefix}{number:04}")
+}
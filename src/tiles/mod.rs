@@ -1,4 +1,11 @@
-pub mod images;
+pub(crate) mod images;
+mod tags;
 mod tile;
 
-pub use tile::{Flags, Special, Terrain, Tile, Transform, TILE_IMAGE_SIZE};
+#[doc(hidden)]
+pub use images::draw_sprite;
+pub use tags::{TagValue, TileTags};
+pub(crate) use tile::TransformStatus;
+pub use tile::{
+    Flags, Special, Terrain, Tile, Transform, TransformOutcome, TransformResult, ALL_TERRAINS,
+};
@@ -0,0 +1,140 @@
+use std::collections::BTreeMap;
+
+/// A value stored in [`TileTags`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TagValue {
+    Integer(i64),
+    Boolean(bool),
+    String(String),
+}
+
+impl TagValue {
+    #[must_use]
+    pub const fn as_integer(&self) -> Option<i64> {
+        match self {
+            Self::Integer(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Boolean(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl From<i64> for TagValue {
+    fn from(value: i64) -> Self {
+        Self::Integer(value)
+    }
+}
+
+impl From<bool> for TagValue {
+    fn from(value: bool) -> Self {
+        Self::Boolean(value)
+    }
+}
+
+impl From<&str> for TagValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+
+impl From<String> for TagValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+/// Custom data attached to a [`Tile`](super::Tile), keyed by name.
+///
+/// The game itself never reads tags. They are meant for scenario scripts,
+/// editors and experimental mechanics that need to remember something about a
+/// tile, and are stored in savegames along with the rest of the map. Prefixing
+/// keys with the name of the script, like `"volcano.eruptions"`, avoids
+/// clashes between scripts.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TileTags {
+    tags: BTreeMap<String, TagValue>,
+}
+
+impl TileTags {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            tags: BTreeMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&TagValue> {
+        self.tags.get(key)
+    }
+
+    /// Sets a tag, returning the previous value if there was one.
+    pub fn set(&mut self, key: &str, value: impl Into<TagValue>) -> Option<TagValue> {
+        self.tags.insert(key.to_owned(), value.into())
+    }
+
+    /// Removes a tag, returning its value if there was one.
+    pub fn remove(&mut self, key: &str) -> Option<TagValue> {
+        self.tags.remove(key)
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.tags.len()
+    }
+
+    /// Iterates over all tags, ordered by key.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &TagValue)> {
+        self.tags.iter().map(|(key, value)| (key.as_str(), value))
+    }
+}
+
+#[test]
+fn test_tile_tags() {
+    let mut tags = TileTags::new();
+    assert!(tags.is_empty());
+
+    assert_eq!(tags.set("volcano.eruptions", 2), None);
+    assert_eq!(tags.set("editor.note", "start here"), None);
+    assert_eq!(tags.set("volcano.eruptions", 3), Some(TagValue::Integer(2)));
+    assert_eq!(tags.len(), 2);
+    assert_eq!(
+        tags.get("volcano.eruptions").and_then(TagValue::as_integer),
+        Some(3)
+    );
+    assert_eq!(
+        tags.get("editor.note").and_then(TagValue::as_str),
+        Some("start here")
+    );
+    assert_eq!(tags.get("editor.note").and_then(TagValue::as_bool), None);
+    assert_eq!(
+        tags.iter().map(|(key, _)| key).collect::<Vec<_>>(),
+        ["editor.note", "volcano.eruptions"]
+    );
+
+    assert_eq!(
+        tags.remove("editor.note"),
+        Some(TagValue::from("start here"))
+    );
+    assert_eq!(tags.get("editor.note"), None);
+}
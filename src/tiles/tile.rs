@@ -1,11 +1,21 @@
-use std::hint::unreachable_unchecked;
+use std::{hint::unreachable_unchecked, ops::Range};
 
 use bitflags::bitflags;
-use image::{imageops, GenericImage, Rgba};
-
-use super::images::get_image;
-
-pub const TILE_IMAGE_SIZE: u32 = 30;
+use image::{Rgba, RgbaImage};
+
+use super::{
+    images::{
+        sprite, DEEP_OCEAN_SPRITES, DESERT_SPRITES, FOREST_SPRITES, GLACIER_SPRITES, HILLS_SPRITES,
+        JUNGLE_SPRITES, LAKE_SPRITES, MOUNTAINS_SPRITES, OCEAN_SPRITES, PLAINS_SPRITES,
+        RIVER_SPRITES, SWAMP_SPRITES, TUNDRA_SPRITES, WATER_WITH_ICE_SHELVES_SPRITES,
+        WATER_WITH_SHORELINE_SPRITES,
+    },
+    TileTags,
+};
+use crate::{
+    game::SpeedModifiers,
+    units::{MovementDomain, UnitClass},
+};
 
 /// The FreeCiv map consists of tiles, which are laid out in a grid of squares.
 /// Technically, FreeCiv supports other shapes, but we will simplify it to
@@ -16,6 +26,8 @@ pub const TILE_IMAGE_SIZE: u32 = 30;
 ///
 /// Tiles can be transformed to other tiles via a [`Transform`].
 ///
+/// Custom data can be attached to tiles as [`TileTags`].
+///
 /// TODO: Food, production and trade calculation.
 #[derive(Debug, Clone)]
 pub struct Tile {
@@ -23,6 +35,7 @@ pub struct Tile {
     pub(crate) special: Special,
     pub(crate) flags: Flags,
     pub(crate) transform_status: TransformStatus,
+    pub(crate) tags: TileTags,
 }
 
 impl Tile {
@@ -35,9 +48,34 @@ impl Tile {
             special,
             flags,
             transform_status: TransformStatus::NotTransforming,
+            tags: TileTags::new(),
         }
     }
 
+    #[must_use]
+    pub const fn terrain(&self) -> Terrain {
+        self.terrain
+    }
+
+    #[must_use]
+    pub const fn special(&self) -> Special {
+        self.special
+    }
+
+    #[must_use]
+    pub const fn flags(&self) -> Flags {
+        self.flags
+    }
+
+    #[must_use]
+    pub const fn tags(&self) -> &TileTags {
+        &self.tags
+    }
+
+    pub const fn tags_mut(&mut self) -> &mut TileTags {
+        &mut self.tags
+    }
+
     /// The cost of moving from this tile (not on it).
     ///
     /// Units in FreeCiv have a specific movement, for example normal units
@@ -48,7 +86,8 @@ impl Tile {
         self.terrain.move_cost()
     }
 
-    /// Attempts to start transforming this tile.
+    /// Attempts to start transforming this tile. The turns of the transform
+    /// table are scaled by the game's [`SpeedModifiers`].
     ///
     /// Returns [`TransformResult::Impossible`] if the transformation was
     /// already done (for example if a tile is already irrigated and cannot be
@@ -58,7 +97,11 @@ impl Tile {
     /// TODO: Add support for farmland and railroad, check worker's skill
     /// level and researched technologies. Also, support multiple workers
     /// transforming at once and multiple transformations at once.
-    pub fn start_transform(&mut self, transform: Transform) -> TransformResult {
+    pub fn start_transform(
+        &mut self,
+        transform: Transform,
+        speed: &SpeedModifiers,
+    ) -> TransformResult {
         let turns = match self.terrain.transform(&transform) {
             TransformOutcome::BuildIrrigation(turns) => {
                 if self.flags.contains(Flags::HAS_IRRIGATION) {
@@ -84,6 +127,7 @@ impl Tile {
             TransformOutcome::Impossible => return TransformResult::Impossible,
             TransformOutcome::TransformTo(_, turns) => turns,
         };
+        let turns = speed.transform_turns(turns);
 
         self.transform_status = TransformStatus::Transforming {
             turns_remaining: turns,
@@ -181,9 +225,9 @@ impl Tile {
         }
     }
 
-    pub fn render<G: GenericImage<Pixel = Rgba<u8>>>(
+    pub fn render(
         &self,
-        base: &mut G,
+        base: &mut RgbaImage,
         north: Option<&Self>,
         north_east: Option<&Self>,
         east: Option<&Self>,
@@ -235,7 +279,60 @@ pub enum Terrain {
     Tundra,
 }
 
+/// All terrains, for example to list their [`Terrain::transforms`].
+pub static ALL_TERRAINS: &[Terrain] = &[
+    Terrain::DeepOcean,
+    Terrain::Desert,
+    Terrain::Forest,
+    Terrain::Glacier,
+    Terrain::Grassland,
+    Terrain::Hills,
+    Terrain::Jungle,
+    Terrain::Lake,
+    Terrain::Mountains,
+    Terrain::Ocean,
+    Terrain::Plains,
+    Terrain::Swamp,
+    Terrain::Tundra,
+];
+
+/// Terrain identifiers, the same as in FreeCiv's classic ruleset. They are
+/// used in the map rows of savegames and by [`World::fingerprint`], so they
+/// must not change.
+///
+/// [`World::fingerprint`]: crate::world::World::fingerprint
+const TERRAIN_IDENTIFIERS: [(Terrain, char); 13] = [
+    (Terrain::DeepOcean, ':'),
+    (Terrain::Desert, 'd'),
+    (Terrain::Forest, 'f'),
+    (Terrain::Glacier, 'a'),
+    (Terrain::Grassland, 'g'),
+    (Terrain::Hills, 'h'),
+    (Terrain::Jungle, 'j'),
+    (Terrain::Lake, '+'),
+    (Terrain::Mountains, 'm'),
+    (Terrain::Ocean, ' '),
+    (Terrain::Plains, 'p'),
+    (Terrain::Swamp, 's'),
+    (Terrain::Tundra, 't'),
+];
+
 impl Terrain {
+    /// The identifier of this terrain in savegames.
+    pub(crate) fn identifier(self) -> char {
+        TERRAIN_IDENTIFIERS
+            .iter()
+            .find(|&&(terrain, _)| terrain == self)
+            .map_or('?', |&(_, identifier)| identifier)
+    }
+
+    pub(crate) fn from_identifier(identifier: char) -> Option<Self> {
+        TERRAIN_IDENTIFIERS
+            .iter()
+            .find(|&&(_, i)| i == identifier)
+            .map(|&(terrain, _)| terrain)
+    }
+
     pub const fn is_water(&self) -> bool {
         matches!(self, Self::DeepOcean | Self::Ocean | Self::Lake)
     }
@@ -261,6 +358,18 @@ impl Terrain {
         )
     }
 
+    /// Whether units of a class can enter this terrain.
+    #[must_use]
+    pub fn passable_for(self, class: &UnitClass) -> bool {
+        let in_domain = match class.domain() {
+            MovementDomain::Land => !self.is_water(),
+            MovementDomain::Sea => self.is_water(),
+            MovementDomain::Air => true,
+        };
+
+        in_domain && !class.impassable().contains(&self)
+    }
+
     /// Movement cost on this terrain. See [`Tile::move_cost`].
     #[must_use]
     pub const fn move_cost(self) -> u8 {
@@ -295,86 +404,100 @@ impl Terrain {
         }
     }
 
+    /// All transforms of this terrain with their outcomes, in the order of
+    /// the [`Transform`] variants. Every transform is listed, including the
+    /// impossible ones. The turns are those at normal game speed, see
+    /// [`SpeedModifiers`].
+    #[must_use]
+    pub const fn transforms(self) -> &'static [(Transform, TransformOutcome)] {
+        use TransformOutcome::{BuildIrrigation, BuildMine, BuildRoad, Impossible, TransformTo};
+
+        match self {
+            Self::DeepOcean => &[
+                (Transform::Irrigation, Impossible),
+                (Transform::Mining, Impossible),
+                (Transform::Road, Impossible),
+                (Transform::Transforming, Impossible),
+            ],
+            Self::Desert => &[
+                (Transform::Irrigation, BuildIrrigation(5)),
+                (Transform::Mining, BuildMine(5)),
+                (Transform::Road, BuildRoad(2)),
+                (Transform::Transforming, TransformTo(Terrain::Plains, 24)),
+            ],
+            Self::Forest => &[
+                (Transform::Irrigation, TransformTo(Terrain::Plains, 5)),
+                (Transform::Mining, TransformTo(Terrain::Swamp, 15)),
+                (Transform::Road, BuildRoad(4)),
+                (Transform::Transforming, TransformTo(Terrain::Grassland, 24)),
+            ],
+            Self::Glacier => &[
+                (Transform::Irrigation, Impossible),
+                (Transform::Mining, BuildMine(10)),
+                (Transform::Road, BuildRoad(4)),
+                (Transform::Transforming, TransformTo(Terrain::Tundra, 24)),
+            ],
+            Self::Grassland => &[
+                (Transform::Irrigation, BuildIrrigation(5)),
+                (Transform::Mining, TransformTo(Terrain::Forest, 10)),
+                (Transform::Road, BuildRoad(2)),
+                (Transform::Transforming, TransformTo(Terrain::Hills, 24)),
+            ],
+            Self::Hills => &[
+                (Transform::Irrigation, BuildIrrigation(10)),
+                (Transform::Mining, BuildMine(10)),
+                (Transform::Road, BuildRoad(4)),
+                (Transform::Transforming, TransformTo(Terrain::Plains, 24)),
+            ],
+            Self::Jungle => &[
+                (Transform::Irrigation, TransformTo(Terrain::Grassland, 15)),
+                (Transform::Mining, TransformTo(Terrain::Forest, 15)),
+                (Transform::Road, BuildRoad(4)),
+                (Transform::Transforming, TransformTo(Terrain::Plains, 24)),
+            ],
+            Self::Lake | Self::Ocean => &[
+                (Transform::Irrigation, Impossible),
+                (Transform::Mining, Impossible),
+                (Transform::Road, Impossible),
+                (Transform::Transforming, TransformTo(Terrain::Swamp, 36)),
+            ],
+            Self::Mountains => &[
+                (Transform::Irrigation, Impossible),
+                (Transform::Mining, BuildMine(10)),
+                (Transform::Road, BuildRoad(6)),
+                (Transform::Transforming, TransformTo(Terrain::Hills, 24)),
+            ],
+            Self::Plains => &[
+                (Transform::Irrigation, BuildIrrigation(5)),
+                (Transform::Mining, TransformTo(Terrain::Forest, 15)),
+                (Transform::Road, BuildRoad(2)),
+                (Transform::Transforming, TransformTo(Terrain::Grassland, 24)),
+            ],
+            Self::Swamp => &[
+                (Transform::Irrigation, TransformTo(Terrain::Grassland, 15)),
+                (Transform::Mining, TransformTo(Terrain::Forest, 15)),
+                (Transform::Road, BuildRoad(4)),
+                (Transform::Transforming, TransformTo(Terrain::Ocean, 36)),
+            ],
+            Self::Tundra => &[
+                (Transform::Irrigation, BuildIrrigation(5)),
+                (Transform::Mining, Impossible),
+                (Transform::Road, BuildRoad(2)),
+                (Transform::Transforming, TransformTo(Terrain::Desert, 24)),
+            ],
+        }
+    }
+
     /// The result of transforming this terrain with a [`Transform`]. The
     /// outcome returns whether this is a possible transformation, what the new
     /// terrain type or flags will be and how many turns it takes.
     const fn transform(self, transform: &Transform) -> TransformOutcome {
-        match self {
-            Self::DeepOcean => TransformOutcome::Impossible,
-            Self::Desert => match transform {
-                Transform::Irrigation => irrigation(5),
-                Transform::Mining => mine(5),
-                Transform::Road => road(2),
-                Transform::Transforming => plains(24),
-            },
-            Self::Forest => match transform {
-                Transform::Irrigation => plains(5),
-                Transform::Mining => swamp(15),
-                Transform::Road => road(4),
-                Transform::Transforming => grassland(24),
-            },
-            Self::Glacier => match transform {
-                Transform::Irrigation => impossible(),
-                Transform::Mining => mine(10),
-                Transform::Road => road(4),
-                Transform::Transforming => tundra(24),
-            },
-            Self::Grassland => match transform {
-                Transform::Irrigation => irrigation(5),
-                Transform::Mining => forest(10),
-                Transform::Road => road(2),
-                Transform::Transforming => hills(24),
-            },
-            Self::Hills => match transform {
-                Transform::Irrigation => irrigation(10),
-                Transform::Mining => mine(10),
-                Transform::Road => road(4),
-                Transform::Transforming => plains(24),
-            },
-            Self::Jungle => match transform {
-                Transform::Irrigation => grassland(15),
-                Transform::Mining => forest(15),
-                Transform::Road => road(4),
-                Transform::Transforming => plains(24),
-            },
-            Self::Lake => match transform {
-                Transform::Irrigation | Transform::Mining | Transform::Road => impossible(),
-                Transform::Transforming => swamp(36),
-            },
-            Self::Mountains => match transform {
-                Transform::Irrigation => impossible(),
-                Transform::Mining => mine(10),
-                Transform::Road => road(6),
-                Transform::Transforming => hills(24),
-            },
-            Self::Ocean => match transform {
-                Transform::Irrigation | Transform::Mining | Transform::Road => impossible(),
-                Transform::Transforming => swamp(36),
-            },
-            Self::Plains => match transform {
-                Transform::Irrigation => irrigation(5),
-                Transform::Mining => forest(15),
-                Transform::Road => road(2),
-                Transform::Transforming => grassland(24),
-            },
-            Self::Swamp => match transform {
-                Transform::Irrigation => grassland(15),
-                Transform::Mining => forest(15),
-                Transform::Road => road(4),
-                Transform::Transforming => ocean(36),
-            },
-            Self::Tundra => match transform {
-                Transform::Irrigation => irrigation(5),
-                Transform::Mining => impossible(),
-                Transform::Road => road(2),
-                Transform::Transforming => desert(24),
-            },
-        }
+        self.transforms()[*transform as usize].1
     }
 
-    fn draw_coastline<G: GenericImage<Pixel = Rgba<u8>>>(
+    fn draw_coastline(
         self,
-        base: &mut G,
+        base: &mut RgbaImage,
         north: Option<Self>,
         east: Option<Self>,
         south: Option<Self>,
@@ -393,51 +516,17 @@ impl Terrain {
             | west.map_or(false, |t| t == Terrain::Glacier);
 
         let img = if any_is_glacier {
-            match (north_water, east_water, south_water, west_water) {
-                (true, true, true, true) => get_image("water_with_ice_shelves_nesw"),
-                (true, true, true, false) => get_image("water_with_ice_shelves_nes"),
-                (true, true, false, true) => get_image("water_with_ice_shelves_new"),
-                (true, false, true, true) => get_image("water_with_ice_shelves_nsw"),
-                (false, true, true, true) => get_image("water_with_ice_shelves_esw"),
-                (true, true, false, false) => get_image("water_with_ice_shelves_ne"),
-                (true, false, true, false) => get_image("water_with_ice_shelves_ns"),
-                (true, false, false, true) => get_image("water_with_ice_shelves_nw"),
-                (false, true, true, false) => get_image("water_with_ice_shelves_es"),
-                (false, true, false, true) => get_image("water_with_ice_shelves_ew"),
-                (false, false, true, true) => get_image("water_with_ice_shelves_sw"),
-                (true, false, false, false) => get_image("water_with_ice_shelves_n"),
-                (false, true, false, false) => get_image("water_with_ice_shelves_e"),
-                (false, false, true, false) => get_image("water_with_ice_shelves_s"),
-                (false, false, false, true) => get_image("water_with_ice_shelves_w"),
-                (false, false, false, false) => get_image("water_with_ice_shelves_none"),
-            }
+            WATER_WITH_ICE_SHELVES_SPRITES.get([north_water, east_water, south_water, west_water])
         } else {
-            match (north_water, east_water, south_water, west_water) {
-                (true, true, true, true) => get_image("water_with_shoreline_nesw"),
-                (true, true, true, false) => get_image("water_with_shoreline_nes"),
-                (true, true, false, true) => get_image("water_with_shoreline_new"),
-                (true, false, true, true) => get_image("water_with_shoreline_nsw"),
-                (false, true, true, true) => get_image("water_with_shoreline_esw"),
-                (true, true, false, false) => get_image("water_with_shoreline_ne"),
-                (true, false, true, false) => get_image("water_with_shoreline_ns"),
-                (true, false, false, true) => get_image("water_with_shoreline_nw"),
-                (false, true, true, false) => get_image("water_with_shoreline_es"),
-                (false, true, false, true) => get_image("water_with_shoreline_ew"),
-                (false, false, true, true) => get_image("water_with_shoreline_sw"),
-                (true, false, false, false) => get_image("water_with_shoreline_n"),
-                (false, true, false, false) => get_image("water_with_shoreline_e"),
-                (false, false, true, false) => get_image("water_with_shoreline_s"),
-                (false, false, false, true) => get_image("water_with_shoreline_w"),
-                (false, false, false, false) => get_image("water_with_shoreline_none"),
-            }
+            WATER_WITH_SHORELINE_SPRITES.get([north_water, east_water, south_water, west_water])
         };
 
-        imageops::overlay(base, img, 0, 0);
+        img.draw(base, 0, 0);
     }
 
-    fn render<G: GenericImage<Pixel = Rgba<u8>>>(
+    fn render(
         self,
-        base: &mut G,
+        base: &mut RgbaImage,
         north: Option<Self>,
         north_east: Option<Self>,
         east: Option<Self>,
@@ -456,180 +545,39 @@ impl Terrain {
         let img = match self {
             Self::DeepOcean => {
                 // TODO: Figure out how the hell this works
-                let tl = get_image("deep_ocean_tl_n");
-                let tr = get_image("deep_ocean_tr_n");
-                let bl = get_image("deep_ocean_bl_n");
-                let br = get_image("deep_ocean_br_n");
-
-                imageops::overlay(base, tl, 0, 0);
-                imageops::overlay(base, tr, 15, 0);
-                imageops::overlay(base, bl, 0, 15);
-                imageops::overlay(base, br, 15, 15);
+                DEEP_OCEAN_SPRITES.draw(base);
 
                 self.draw_coastline(base, north, east, south, west);
 
                 return;
             }
-            Self::Desert => match (north_same, east_same, south_same, west_same) {
-                (true, true, true, true) => get_image("desert_nesw"),
-                (true, true, true, false) => get_image("desert_nes"),
-                (true, true, false, true) => get_image("desert_new"),
-                (true, false, true, true) => get_image("desert_nsw"),
-                (false, true, true, true) => get_image("desert_esw"),
-                (true, true, false, false) => get_image("desert_ne"),
-                (true, false, true, false) => get_image("desert_ns"),
-                (true, false, false, true) => get_image("desert_nw"),
-                (false, true, true, false) => get_image("desert_es"),
-                (false, true, false, true) => get_image("desert_ew"),
-                (false, false, true, true) => get_image("desert_sw"),
-                (true, false, false, false) => get_image("desert_n"),
-                (false, true, false, false) => get_image("desert_e"),
-                (false, false, true, false) => get_image("desert_s"),
-                (false, false, false, true) => get_image("desert_w"),
-                (false, false, false, false) => get_image("desert_none"),
-            },
-            Self::Forest => match (east_same, west_same) {
-                (true, true) => get_image("forest_ew"),
-                (true, false) => get_image("forest_e"),
-                (false, true) => get_image("forest_w"),
-                (false, false) => get_image("forest_not_ew"),
-            },
-            Self::Glacier => match (north_same, east_same, south_same, west_same) {
-                (true, true, true, true) => get_image("glacier_nesw"),
-                (true, true, true, false) => get_image("glacier_nes"),
-                (true, true, false, true) => get_image("glacier_new"),
-                (true, false, true, true) => get_image("glacier_nsw"),
-                (false, true, true, true) => get_image("glacier_esw"),
-                (true, true, false, false) => get_image("glacier_ne"),
-                (true, false, true, false) => get_image("glacier_ns"),
-                (true, false, false, true) => get_image("glacier_nw"),
-                (false, true, true, false) => get_image("glacier_es"),
-                (false, true, false, true) => get_image("glacier_ew"),
-                (false, false, true, true) => get_image("glacier_sw"),
-                (true, false, false, false) => get_image("glacier_n"),
-                (false, true, false, false) => get_image("glacier_e"),
-                (false, false, true, false) => get_image("glacier_s"),
-                (false, false, false, true) => get_image("glacier_w"),
-                (false, false, false, false) => get_image("glacier_none"),
-            },
-            Self::Grassland => get_image("grassland"),
-            Self::Hills => match (east_same, west_same) {
-                (true, true) => get_image("hills_ew"),
-                (true, false) => get_image("hills_e"),
-                (false, true) => get_image("hills_w"),
-                (false, false) => get_image("hills_not_ew"),
-            },
-            Self::Jungle => match (north_same, east_same, south_same, west_same) {
-                (true, true, true, true) => get_image("jungle_nesw"),
-                (true, true, true, false) => get_image("jungle_nes"),
-                (true, true, false, true) => get_image("jungle_new"),
-                (true, false, true, true) => get_image("jungle_nsw"),
-                (false, true, true, true) => get_image("jungle_esw"),
-                (true, true, false, false) => get_image("jungle_ne"),
-                (true, false, true, false) => get_image("jungle_ns"),
-                (true, false, false, true) => get_image("jungle_nw"),
-                (false, true, true, false) => get_image("jungle_es"),
-                (false, true, false, true) => get_image("jungle_ew"),
-                (false, false, true, true) => get_image("jungle_sw"),
-                (true, false, false, false) => get_image("jungle_n"),
-                (false, true, false, false) => get_image("jungle_e"),
-                (false, false, true, false) => get_image("jungle_s"),
-                (false, false, false, true) => get_image("jungle_w"),
-                (false, false, false, false) => get_image("jungle_none"),
-            },
+            Self::Desert => DESERT_SPRITES.get([north_same, east_same, south_same, west_same]),
+            Self::Forest => FOREST_SPRITES.get(east_same, west_same),
+            Self::Glacier => GLACIER_SPRITES.get([north_same, east_same, south_same, west_same]),
+            Self::Grassland => sprite!("grassland"),
+            Self::Hills => HILLS_SPRITES.get(east_same, west_same),
+            Self::Jungle => JUNGLE_SPRITES.get([north_same, east_same, south_same, west_same]),
             Self::Lake => {
-                let tl = get_image("lake_tl_n");
-                let tr = get_image("lake_tr_n");
-                let bl = get_image("lake_bl_n");
-                let br = get_image("lake_br_n");
-
-                imageops::overlay(base, tl, 0, 0);
-                imageops::overlay(base, tr, 15, 0);
-                imageops::overlay(base, bl, 0, 15);
-                imageops::overlay(base, br, 15, 15);
+                LAKE_SPRITES.draw(base);
 
                 self.draw_coastline(base, north, east, south, west);
 
                 return;
             }
-            Self::Mountains => match (east_same, west_same) {
-                (true, true) => get_image("mountains_ew"),
-                (true, false) => get_image("mountains_e"),
-                (false, true) => get_image("mountains_w"),
-                (false, false) => get_image("mountains_not_ew"),
-            },
+            Self::Mountains => MOUNTAINS_SPRITES.get(east_same, west_same),
             Self::Ocean => {
-                let tl = get_image("ocean_tl_n");
-                let tr = get_image("ocean_tr_n");
-                let bl = get_image("ocean_bl_n");
-                let br = get_image("ocean_br_n");
-
-                imageops::overlay(base, tl, 0, 0);
-                imageops::overlay(base, tr, 15, 0);
-                imageops::overlay(base, bl, 0, 15);
-                imageops::overlay(base, br, 15, 15);
+                OCEAN_SPRITES.draw(base);
 
                 self.draw_coastline(base, north, east, south, west);
 
                 return;
             }
-            Self::Plains => match (north_same, east_same, south_same, west_same) {
-                (true, true, true, true) => get_image("plains_nesw"),
-                (true, true, true, false) => get_image("plains_nes"),
-                (true, true, false, true) => get_image("plains_new"),
-                (true, false, true, true) => get_image("plains_nsw"),
-                (false, true, true, true) => get_image("plains_esw"),
-                (true, true, false, false) => get_image("plains_ne"),
-                (true, false, true, false) => get_image("plains_ns"),
-                (true, false, false, true) => get_image("plains_nw"),
-                (false, true, true, false) => get_image("plains_es"),
-                (false, true, false, true) => get_image("plains_ew"),
-                (false, false, true, true) => get_image("plains_sw"),
-                (true, false, false, false) => get_image("plains_n"),
-                (false, true, false, false) => get_image("plains_e"),
-                (false, false, true, false) => get_image("plains_s"),
-                (false, false, false, true) => get_image("plains_w"),
-                (false, false, false, false) => get_image("plains_none"),
-            },
-            Self::Swamp => match (north_same, east_same, south_same, west_same) {
-                (true, true, true, true) => get_image("swamp_nesw"),
-                (true, true, true, false) => get_image("swamp_nes"),
-                (true, true, false, true) => get_image("swamp_new"),
-                (true, false, true, true) => get_image("swamp_nsw"),
-                (false, true, true, true) => get_image("swamp_esw"),
-                (true, true, false, false) => get_image("swamp_ne"),
-                (true, false, true, false) => get_image("swamp_ns"),
-                (true, false, false, true) => get_image("swamp_nw"),
-                (false, true, true, false) => get_image("swamp_es"),
-                (false, true, false, true) => get_image("swamp_ew"),
-                (false, false, true, true) => get_image("swamp_sw"),
-                (true, false, false, false) => get_image("swamp_n"),
-                (false, true, false, false) => get_image("swamp_e"),
-                (false, false, true, false) => get_image("swamp_s"),
-                (false, false, false, true) => get_image("swamp_w"),
-                (false, false, false, false) => get_image("swamp_none"),
-            },
-            Self::Tundra => match (north_same, east_same, south_same, west_same) {
-                (true, true, true, true) => get_image("tundra_nesw"),
-                (true, true, true, false) => get_image("tundra_nes"),
-                (true, true, false, true) => get_image("tundra_new"),
-                (true, false, true, true) => get_image("tundra_nsw"),
-                (false, true, true, true) => get_image("tundra_esw"),
-                (true, true, false, false) => get_image("tundra_ne"),
-                (true, false, true, false) => get_image("tundra_ns"),
-                (true, false, false, true) => get_image("tundra_nw"),
-                (false, true, true, false) => get_image("tundra_es"),
-                (false, true, false, true) => get_image("tundra_ew"),
-                (false, false, true, true) => get_image("tundra_sw"),
-                (true, false, false, false) => get_image("tundra_n"),
-                (false, true, false, false) => get_image("tundra_e"),
-                (false, false, true, false) => get_image("tundra_s"),
-                (false, false, false, true) => get_image("tundra_w"),
-                (false, false, false, false) => get_image("tundra_none"),
-            },
+            Self::Plains => PLAINS_SPRITES.get([north_same, east_same, south_same, west_same]),
+            Self::Swamp => SWAMP_SPRITES.get([north_same, east_same, south_same, west_same]),
+            Self::Tundra => TUNDRA_SPRITES.get([north_same, east_same, south_same, west_same]),
         };
 
-        imageops::overlay(base, img, 0, 0);
+        img.draw(base, 0, 0);
     }
 
     pub(crate) fn random() -> Self {
@@ -652,84 +600,24 @@ impl Terrain {
     }
 }
 
-#[inline]
-const fn irrigation(turns: u8) -> TransformOutcome {
-    TransformOutcome::BuildIrrigation(turns)
-}
-
-#[inline]
-const fn mine(turns: u8) -> TransformOutcome {
-    TransformOutcome::BuildMine(turns)
-}
-
-#[inline]
-const fn road(turns: u8) -> TransformOutcome {
-    TransformOutcome::BuildRoad(turns)
-}
-
-#[inline]
-const fn railroad() -> TransformOutcome {
-    road(4)
-}
-
-#[inline]
-const fn desert(turns: u8) -> TransformOutcome {
-    TransformOutcome::TransformTo(Terrain::Desert, turns)
-}
-
-#[inline]
-const fn forest(turns: u8) -> TransformOutcome {
-    TransformOutcome::TransformTo(Terrain::Forest, turns)
-}
-
-#[inline]
-const fn grassland(turns: u8) -> TransformOutcome {
-    TransformOutcome::TransformTo(Terrain::Grassland, turns)
-}
-
-#[inline]
-const fn hills(turns: u8) -> TransformOutcome {
-    TransformOutcome::TransformTo(Terrain::Hills, turns)
-}
-
-#[inline]
-const fn ocean(turns: u8) -> TransformOutcome {
-    TransformOutcome::TransformTo(Terrain::Ocean, turns)
-}
-
-#[inline]
-const fn plains(turns: u8) -> TransformOutcome {
-    TransformOutcome::TransformTo(Terrain::Plains, turns)
-}
-
-#[inline]
-const fn swamp(turns: u8) -> TransformOutcome {
-    TransformOutcome::TransformTo(Terrain::Swamp, turns)
-}
-
-#[inline]
-const fn tundra(turns: u8) -> TransformOutcome {
-    TransformOutcome::TransformTo(Terrain::Tundra, turns)
-}
-
-#[inline]
-const fn impossible() -> TransformOutcome {
-    TransformOutcome::Impossible
-}
-
-/// The outcome of a transform from a [`Terrain`] with a [`Transform`].
-#[derive(PartialEq, Eq)]
-enum TransformOutcome {
+/// The outcome of a transform from a [`Terrain`] with a [`Transform`]. The
+/// numbers are the turns a worker needs.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TransformOutcome {
+    /// Irrigates the tile.
     BuildIrrigation(u8),
+    /// Builds a mine on the tile.
     BuildMine(u8),
+    /// Builds a road on the tile.
     BuildRoad(u8),
+    /// Changes the terrain of the tile.
     TransformTo(Terrain, u8),
     Impossible,
 }
 
 /// Special resource that may be present on a [`Tile`]. This grants additional
 /// food, production or trade points.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Special {
     None,
     Oasis,
@@ -754,34 +642,76 @@ pub enum Special {
     Furs,
 }
 
+/// Special resource identifiers, used in the resource rows of savegames and
+/// by [`World::fingerprint`](crate::world::World::fingerprint), so they must
+/// not change.
+const SPECIAL_IDENTIFIERS: [(Special, char); 21] = [
+    (Special::None, '-'),
+    (Special::Oasis, 'o'),
+    (Special::Oil, 'O'),
+    (Special::Pheasant, 'p'),
+    (Special::Silk, 's'),
+    (Special::Ivory, 'i'),
+    (Special::Resources, 'r'),
+    (Special::Coal, 'c'),
+    (Special::Wine, 'w'),
+    (Special::Gems, 'e'),
+    (Special::Fruit, 'f'),
+    (Special::Fish, 'y'),
+    (Special::Gold, 'g'),
+    (Special::Iron, 'n'),
+    (Special::Whales, 'h'),
+    (Special::Buffalo, 'b'),
+    (Special::Wheat, 't'),
+    (Special::Peat, 'a'),
+    (Special::Spice, 'x'),
+    (Special::Game, 'm'),
+    (Special::Furs, 'u'),
+];
+
 impl Special {
-    fn render<G: GenericImage<Pixel = Rgba<u8>>>(&self, base: &mut G) {
+    /// The identifier of this special resource in savegames.
+    pub(crate) fn identifier(self) -> char {
+        SPECIAL_IDENTIFIERS
+            .iter()
+            .find(|&&(special, _)| special == self)
+            .map_or('?', |&(_, identifier)| identifier)
+    }
+
+    pub(crate) fn from_identifier(identifier: char) -> Option<Self> {
+        SPECIAL_IDENTIFIERS
+            .iter()
+            .find(|&&(_, i)| i == identifier)
+            .map(|&(special, _)| special)
+    }
+
+    fn render(&self, base: &mut RgbaImage) {
         let img = match self {
             Self::None => return,
-            Self::Oasis => get_image("oasis"),
-            Self::Oil => get_image("oil"),
-            Self::Pheasant => get_image("pheasant"),
-            Self::Silk => get_image("silk"),
-            Self::Ivory => get_image("ivory"),
-            Self::Resources => get_image("grassland_resources"), // TODO: Dynamic on river
-            Self::Coal => get_image("coal"),
-            Self::Wine => get_image("wine"),
-            Self::Gems => get_image("gems"),
-            Self::Fruit => get_image("fruit"),
-            Self::Fish => get_image("fish"),
-            Self::Gold => get_image("gold"),
-            Self::Iron => get_image("iron"),
-            Self::Whales => get_image("whales"),
-            Self::Buffalo => get_image("buffalo"),
-            Self::Wheat => get_image("wheat"),
-            Self::Peat => get_image("peat"),
-            Self::Spice => get_image("spice"),
-            Self::Game => get_image("tundra_game"), // TODO? Does our version support game on
+            Self::Oasis => sprite!("oasis"),
+            Self::Oil => sprite!("oil"),
+            Self::Pheasant => sprite!("pheasant"),
+            Self::Silk => sprite!("silk"),
+            Self::Ivory => sprite!("ivory"),
+            Self::Resources => sprite!("grassland_resources"), // TODO: Dynamic on river
+            Self::Coal => sprite!("coal"),
+            Self::Wine => sprite!("wine"),
+            Self::Gems => sprite!("gems"),
+            Self::Fruit => sprite!("fruit"),
+            Self::Fish => sprite!("fish"),
+            Self::Gold => sprite!("gold"),
+            Self::Iron => sprite!("iron"),
+            Self::Whales => sprite!("whales"),
+            Self::Buffalo => sprite!("buffalo"),
+            Self::Wheat => sprite!("wheat"),
+            Self::Peat => sprite!("peat"),
+            Self::Spice => sprite!("spice"),
+            Self::Game => sprite!("tundra_game"), // TODO? Does our version support game on
             // forest?
-            Self::Furs => get_image("furs"),
+            Self::Furs => sprite!("furs"),
         };
 
-        imageops::overlay(base, img, 0, 0);
+        img.draw(base, 0, 0);
     }
 }
 
@@ -808,9 +738,9 @@ bitflags! {
 }
 
 impl Flags {
-    fn render<G: GenericImage<Pixel = Rgba<u8>>>(
+    fn render(
         &self,
-        base: &mut G,
+        base: &mut RgbaImage,
         north: Option<Self>,
         east: Option<Self>,
         south: Option<Self>,
@@ -822,61 +752,59 @@ impl Flags {
             let river_south = south.map_or(false, |f| f.contains(Flags::HAS_RIVER));
             let river_west = west.map_or(false, |f| f.contains(Flags::HAS_RIVER));
 
-            let img = match (river_north, river_east, river_south, river_west) {
-                (true, true, true, true) => get_image("river_nesw"),
-                (true, true, true, false) => get_image("river_nes"),
-                (true, true, false, true) => get_image("river_new"),
-                (true, false, true, true) => get_image("river_nsw"),
-                (false, true, true, true) => get_image("river_esw"),
-                (true, true, false, false) => get_image("river_ne"),
-                (true, false, true, false) => get_image("river_ns"),
-                (true, false, false, true) => get_image("river_nw"),
-                (false, true, true, false) => get_image("river_es"),
-                (false, true, false, true) => get_image("river_ew"),
-                (false, false, true, true) => get_image("river_sw"),
-                (true, false, false, false) => get_image("river_n"),
-                (false, true, false, false) => get_image("river_e"),
-                (false, false, true, false) => get_image("river_s"),
-                (false, false, false, true) => get_image("river_w"),
-                (false, false, false, false) => get_image("river"),
-            };
+            let img = RIVER_SPRITES.get([river_north, river_east, river_south, river_west]);
 
-            imageops::overlay(base, img, 0, 0);
+            img.draw(base, 0, 0);
         }
 
-        if self.contains(Self::HAS_ROAD) {
-            // TODO: When we have ported the road files
-            todo!()
+        // The tileset has no road sprites, so roads and railroads are drawn as
+        // bands towards the connected neighbours.
+        if self.intersects(Self::HAS_ROAD | Self::HAS_RAILROAD) {
+            let connected = |flags: Option<Self>| {
+                flags.is_some_and(|flags| flags.intersects(Self::HAS_ROAD | Self::HAS_RAILROAD))
+            };
+            draw_connections(
+                base,
+                ROAD_COLOR,
+                ROAD_WIDTH_DIVISOR,
+                [north, east, south, west].map(connected),
+            );
         }
 
         if self.contains(Self::HAS_IRRIGATION) {
-            let img = get_image("irrigation");
-            imageops::overlay(base, img, 0, 0);
+            let img = sprite!("irrigation");
+            img.draw(base, 0, 0);
         }
 
         if self.contains(Self::HAS_MINE) {
-            let img = get_image("mine");
-            imageops::overlay(base, img, 0, 0);
+            let img = sprite!("mine");
+            img.draw(base, 0, 0);
         }
 
         if self.contains(Self::HAS_RAILROAD) {
-            // TODO: Seems to be missing in the tileset
-            todo!()
+            let connected =
+                |flags: Option<Self>| flags.is_some_and(|flags| flags.contains(Self::HAS_RAILROAD));
+            draw_connections(
+                base,
+                RAILROAD_COLOR,
+                RAILROAD_WIDTH_DIVISOR,
+                [north, east, south, west].map(connected),
+            );
         }
 
         if self.contains(Self::HAS_RUINS) {
-            let img = get_image("ruins");
-            imageops::overlay(base, img, 0, 0);
+            let img = sprite!("ruins");
+            img.draw(base, 0, 0);
         }
 
         if self.contains(Self::HAS_POLLUTION) {
-            let img = get_image("pollution");
-            imageops::overlay(base, img, 0, 0);
+            let img = sprite!("pollution");
+            img.draw(base, 0, 0);
         }
 
         if self.contains(Self::HAS_FORT) {
-            // TODO: We seem to have the images, but idk which ones are which
-            todo!()
+            let img = sprite!("fortress");
+            img.draw(base, 0, 0);
         }
 
         if self.contains(Self::HAS_NUCLEAR_FALLOUT) {
@@ -884,19 +812,59 @@ impl Flags {
         }
 
         if self.contains(Self::HAS_HUT) {
-            let img = get_image("village");
-            imageops::overlay(base, img, 0, 0);
+            let img = sprite!("village");
+            img.draw(base, 0, 0);
         }
 
         if self.contains(Self::HAS_FARMLAND) {
-            let img = get_image("farmland");
-            imageops::overlay(base, img, 0, 0);
+            let img = sprite!("farmland");
+            img.draw(base, 0, 0);
         }
 
         // TODO: City definitely shouldn't be a flag
     }
 }
 
+/// The color roads are drawn in.
+const ROAD_COLOR: Rgba<u8> = Rgba([150, 110, 60, 255]);
+/// The color railroads are drawn in, on top of roads.
+const RAILROAD_COLOR: Rgba<u8> = Rgba([60, 60, 60, 255]);
+/// Roads are a sixteenth of the tile size wide on each side of their middle.
+const ROAD_WIDTH_DIVISOR: u32 = 16;
+/// Railroads are half as wide as roads.
+const RAILROAD_WIDTH_DIVISOR: u32 = 32;
+
+/// Draws a square in the middle of the tile and a band from it to each of the
+/// north, east, south and west edges that is connected.
+fn draw_connections(base: &mut RgbaImage, color: Rgba<u8>, divisor: u32, connected: [bool; 4]) {
+    let size = base.width().min(base.height());
+    let middle = size / 2;
+    let half_width = (size / divisor).max(1).min(middle);
+    let (low, high) = (middle - half_width, middle + half_width);
+    let mut fill = |xs: Range<u32>, ys: Range<u32>| {
+        for y in ys {
+            for x in xs.clone() {
+                base.put_pixel(x, y, color);
+            }
+        }
+    };
+
+    let [north, east, south, west] = connected;
+    fill(low..high, low..high);
+    if north {
+        fill(low..high, 0..low);
+    }
+    if east {
+        fill(high..size, low..high);
+    }
+    if south {
+        fill(low..high, high..size);
+    }
+    if west {
+        fill(0..low, low..high);
+    }
+}
+
 /// A possibly ongoing transformation on a [`Tile`].
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub(crate) enum TransformStatus {
@@ -932,3 +900,92 @@ pub enum WorkerSkill {
     Basic,
     Advanced,
 }
+
+#[test]
+fn test_passable_for() {
+    use crate::units::{AIR, BIG_LAND, LAND, SEA};
+
+    assert!(Terrain::Mountains.passable_for(&LAND));
+    assert!(!Terrain::Mountains.passable_for(&BIG_LAND));
+    assert!(Terrain::Plains.passable_for(&BIG_LAND));
+    assert!(!Terrain::Ocean.passable_for(&LAND));
+    assert!(Terrain::Ocean.passable_for(&SEA));
+    assert!(Terrain::Lake.passable_for(&SEA));
+    assert!(!Terrain::Grassland.passable_for(&SEA));
+    assert!(Terrain::Ocean.passable_for(&AIR));
+    assert!(Terrain::Mountains.passable_for(&AIR));
+}
+
+#[test]
+fn test_transforms() {
+    let order = [
+        Transform::Irrigation,
+        Transform::Mining,
+        Transform::Road,
+        Transform::Transforming,
+    ];
+    for terrain in ALL_TERRAINS {
+        let transforms = terrain.transforms();
+        assert_eq!(
+            transforms.iter().map(|(t, _)| *t).collect::<Vec<_>>(),
+            order
+        );
+        for (transform, outcome) in transforms {
+            assert_eq!(terrain.transform(transform), *outcome);
+        }
+    }
+
+    assert_eq!(
+        Terrain::Desert.transform(&Transform::Irrigation),
+        TransformOutcome::BuildIrrigation(5)
+    );
+    assert_eq!(
+        Terrain::Swamp.transform(&Transform::Transforming),
+        TransformOutcome::TransformTo(Terrain::Ocean, 36)
+    );
+    assert!(Terrain::Ocean
+        .transforms()
+        .iter()
+        .all(|(transform, outcome)| *transform == Transform::Transforming
+            || *outcome == TransformOutcome::Impossible));
+}
+
+#[test]
+fn test_render_roads() {
+    let sprite_size = sprite!("fog").width();
+    let road = Tile::new(Terrain::Grassland, Special::None, Flags::HAS_ROAD);
+    let railroad = Tile::new(
+        Terrain::Grassland,
+        Special::None,
+        Flags::HAS_ROAD | Flags::HAS_RAILROAD,
+    );
+    let render = |tile: &Tile, east: &Tile| {
+        let mut base = RgbaImage::new(sprite_size, sprite_size);
+        tile.render(
+            &mut base,
+            None,
+            None,
+            Some(east),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        base
+    };
+    let middle = sprite_size / 2;
+
+    // Roads lead to the eastern neighbour only.
+    let image = render(&road, &railroad);
+    assert_eq!(*image.get_pixel(middle, middle), ROAD_COLOR);
+    assert_eq!(*image.get_pixel(sprite_size - 1, middle), ROAD_COLOR);
+    assert_ne!(*image.get_pixel(middle, 0), ROAD_COLOR);
+
+    // Railroads are drawn on top of the road, but only connect to railroads.
+    let image = render(&railroad, &road);
+    assert_eq!(*image.get_pixel(middle, middle), RAILROAD_COLOR);
+    assert_eq!(*image.get_pixel(sprite_size - 1, middle), ROAD_COLOR);
+    let image = render(&railroad, &railroad);
+    assert_eq!(*image.get_pixel(sprite_size - 1, middle), RAILROAD_COLOR);
+}
@@ -0,0 +1,19 @@
+//! Unit types, their classes and the units on the map.
+//!
+//! ```
+//! use freeciv_rs::units::{MovementDomain, ALL_UNIT_TYPES};
+//!
+//! let ships = ALL_UNIT_TYPES
+//!     .iter()
+//!     .filter(|kind| kind.class().domain() == MovementDomain::Sea)
+//!     .map(|kind| kind.name());
+//! assert!(ships.eq(["Trireme"]));
+//! ```
+
+mod unit;
+mod unit_class;
+mod unit_type;
+
+pub use unit::{Activity, Unit, UnitId, SINGLE_MOVE};
+pub use unit_class::*;
+pub use unit_type::*;
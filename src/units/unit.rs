@@ -0,0 +1,102 @@
+use super::UnitType;
+use crate::game::PlayerId;
+
+/// The number of move fragments in one whole move. Movement along roads or of
+/// units that ignore terrain costs a single fragment.
+pub const SINGLE_MOVE: u8 = 3;
+
+/// Unique identifier of a [`Unit`] within a game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UnitId(pub(crate) u32);
+
+/// What a unit is doing across turns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Activity {
+    /// The unit is waiting for orders.
+    Idle,
+    /// The unit will be fortified at the end of the turn.
+    Fortifying,
+    /// The unit is fortified and defends better.
+    Fortified,
+    /// The unit is building a road on its tile.
+    BuildingRoad,
+}
+
+/// A single unit on the map, owned by a player.
+#[derive(Debug, Clone)]
+pub struct Unit {
+    pub(crate) id: UnitId,
+    pub(crate) owner: PlayerId,
+    pub(crate) kind: &'static UnitType,
+    pub(crate) x: usize,
+    pub(crate) y: usize,
+    pub(crate) moves_left: u8,
+    pub(crate) hitpoints: u8,
+    pub(crate) activity: Activity,
+}
+
+impl Unit {
+    /// Creates a new idle unit with full movement points and hitpoints.
+    #[must_use]
+    pub const fn new(
+        id: UnitId,
+        owner: PlayerId,
+        kind: &'static UnitType,
+        x: usize,
+        y: usize,
+    ) -> Self {
+        Self {
+            id,
+            owner,
+            kind,
+            x,
+            y,
+            moves_left: kind.moves() * SINGLE_MOVE,
+            hitpoints: kind.hitpoints(),
+            activity: Activity::Idle,
+        }
+    }
+
+    #[must_use]
+    pub const fn id(&self) -> UnitId {
+        self.id
+    }
+
+    #[must_use]
+    pub const fn owner(&self) -> PlayerId {
+        self.owner
+    }
+
+    #[must_use]
+    pub const fn kind(&self) -> &'static UnitType {
+        self.kind
+    }
+
+    /// The coordinates of the tile this unit is on.
+    #[must_use]
+    pub const fn position(&self) -> (usize, usize) {
+        (self.x, self.y)
+    }
+
+    /// Movement left this turn, in fragments of [`SINGLE_MOVE`].
+    #[must_use]
+    pub const fn moves_left(&self) -> u8 {
+        self.moves_left
+    }
+
+    /// Whether the unit has not moved yet this turn.
+    #[must_use]
+    pub const fn has_full_moves(&self) -> bool {
+        self.moves_left == self.kind.moves() * SINGLE_MOVE
+    }
+
+    #[must_use]
+    pub const fn hitpoints(&self) -> u8 {
+        self.hitpoints
+    }
+
+    #[must_use]
+    pub const fn activity(&self) -> Activity {
+        self.activity
+    }
+}
@@ -0,0 +1,99 @@
+use crate::tiles::Terrain;
+
+/// Where units of a [`UnitClass`] move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MovementDomain {
+    /// The unit moves on land and cannot enter water.
+    Land,
+    /// The unit moves on water and cannot enter land.
+    Sea,
+    /// The unit flies and can enter any terrain.
+    Air,
+}
+
+/// A group of unit types that share the terrain they can move on. Rulesets
+/// define their own classes, the ones here follow FreeCiv's `civ2civ3`
+/// ruleset.
+///
+/// Refer to the wiki for more information:
+/// <https://freeciv.fandom.com/wiki/Unit_class>.
+#[derive(Debug)]
+pub struct UnitClass {
+    name: &'static str,
+    domain: MovementDomain,
+    /// Terrain in the class's domain that its units still cannot enter.
+    impassable: &'static [Terrain],
+}
+
+impl PartialEq<UnitClass> for UnitClass {
+    fn eq(&self, other: &UnitClass) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Eq for UnitClass {}
+
+impl UnitClass {
+    /// Creates a unit class, for example for a ruleset other than
+    /// `civ2civ3`. Classes are compared by name, so it should be unique.
+    #[must_use]
+    pub const fn new(
+        name: &'static str,
+        domain: MovementDomain,
+        impassable: &'static [Terrain],
+    ) -> Self {
+        Self {
+            name,
+            domain,
+            impassable,
+        }
+    }
+
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    #[must_use]
+    pub const fn domain(&self) -> MovementDomain {
+        self.domain
+    }
+
+    /// Terrain in the class's domain that its units still cannot enter.
+    #[must_use]
+    pub const fn impassable(&self) -> &'static [Terrain] {
+        self.impassable
+    }
+}
+
+pub static LAND: UnitClass = UnitClass {
+    name: "Land",
+    domain: MovementDomain::Land,
+    impassable: &[],
+};
+
+/// Wheeled land units that cannot cross rough terrain.
+pub static BIG_LAND: UnitClass = UnitClass {
+    name: "Big Land",
+    domain: MovementDomain::Land,
+    impassable: &[
+        Terrain::Glacier,
+        Terrain::Jungle,
+        Terrain::Mountains,
+        Terrain::Swamp,
+    ],
+};
+
+pub static SEA: UnitClass = UnitClass {
+    name: "Sea",
+    domain: MovementDomain::Sea,
+    impassable: &[],
+};
+
+pub static AIR: UnitClass = UnitClass {
+    name: "Air",
+    domain: MovementDomain::Air,
+    impassable: &[],
+};
+
+pub static ALL_UNIT_CLASSES: &[&UnitClass] = &[&LAND, &BIG_LAND, &SEA, &AIR];
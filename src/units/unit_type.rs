@@ -0,0 +1,235 @@
+use bitflags::bitflags;
+
+use super::{UnitClass, LAND, SEA};
+use crate::research::{
+    Technology, BRONZE_WORKING, HORSEBACK_RIDING, MAP_MAKING, SEAFARING, WARRIOR_CODE, WRITING,
+};
+
+bitflags! {
+    /// Abilities of a [`UnitType`].
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct UnitFlags: u8 {
+        /// The unit can found new cities.
+        const CITIES =       0b0000_0001;
+        /// The unit can build irrigation, mines, roads and transform terrain.
+        const SETTLERS =     0b0000_0010;
+        /// Moving costs the same on every terrain.
+        const IGTER =        0b0000_0100;
+        /// The unit is not a military unit and cannot attack or fortify.
+        const NON_MILITARY = 0b0000_1000;
+        /// The unit is not affected by enemy zones of control.
+        const IGZOC =        0b0001_0000;
+    }
+}
+
+/// A kind of unit, such as settlers or warriors. Individual units on the map
+/// are [`Unit`](super::Unit)s that refer to their type.
+///
+/// Refer to the wiki for more information:
+/// <https://freeciv.fandom.com/wiki/Units>.
+#[derive(Debug)]
+pub struct UnitType {
+    name: &'static str,
+    attack: u8,
+    defense: u8,
+    moves: u8,
+    hitpoints: u8,
+    class: &'static UnitClass,
+    flags: UnitFlags,
+    requirement: Option<&'static Technology>,
+    build_cost: u16,
+}
+
+impl PartialEq<UnitType> for UnitType {
+    fn eq(&self, other: &UnitType) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Eq for UnitType {}
+
+impl UnitType {
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    #[must_use]
+    pub const fn attack(&self) -> u8 {
+        self.attack
+    }
+
+    #[must_use]
+    pub const fn defense(&self) -> u8 {
+        self.defense
+    }
+
+    /// Movement points this unit has at the start of each turn, in whole
+    /// moves. See [`Tile::move_cost`](crate::tiles::Tile::move_cost).
+    #[must_use]
+    pub const fn moves(&self) -> u8 {
+        self.moves
+    }
+
+    #[must_use]
+    pub const fn hitpoints(&self) -> u8 {
+        self.hitpoints
+    }
+
+    /// The class of this unit, which decides the terrain it can move on.
+    #[must_use]
+    pub const fn class(&self) -> &'static UnitClass {
+        self.class
+    }
+
+    #[must_use]
+    pub const fn flags(&self) -> UnitFlags {
+        self.flags
+    }
+
+    /// The shields needed to build this unit at normal game speed, see
+    /// [`Game::production_cost`](crate::game::Game::production_cost).
+    #[must_use]
+    pub const fn build_cost(&self) -> u16 {
+        self.build_cost
+    }
+
+    /// The technology required to build this unit, if any.
+    #[must_use]
+    pub const fn requirement(&self) -> Option<&'static Technology> {
+        self.requirement
+    }
+}
+
+pub static SETTLERS: UnitType = UnitType {
+    name: "Settlers",
+    attack: 0,
+    defense: 1,
+    moves: 1,
+    hitpoints: 20,
+    class: &LAND,
+    flags: UnitFlags::CITIES
+        .union(UnitFlags::SETTLERS)
+        .union(UnitFlags::NON_MILITARY),
+    requirement: None,
+    build_cost: 30,
+};
+
+pub static WORKERS: UnitType = UnitType {
+    name: "Workers",
+    attack: 0,
+    defense: 1,
+    moves: 1,
+    hitpoints: 10,
+    class: &LAND,
+    flags: UnitFlags::SETTLERS.union(UnitFlags::NON_MILITARY),
+    requirement: None,
+    build_cost: 30,
+};
+
+pub static EXPLORER: UnitType = UnitType {
+    name: "Explorer",
+    attack: 0,
+    defense: 1,
+    moves: 3,
+    hitpoints: 10,
+    class: &LAND,
+    flags: UnitFlags::IGTER
+        .union(UnitFlags::IGZOC)
+        .union(UnitFlags::NON_MILITARY),
+    requirement: Some(&SEAFARING),
+    build_cost: 30,
+};
+
+pub static WARRIORS: UnitType = UnitType {
+    name: "Warriors",
+    attack: 1,
+    defense: 1,
+    moves: 1,
+    hitpoints: 10,
+    class: &LAND,
+    flags: UnitFlags::empty(),
+    requirement: None,
+    build_cost: 10,
+};
+
+pub static PHALANX: UnitType = UnitType {
+    name: "Phalanx",
+    attack: 1,
+    defense: 2,
+    moves: 1,
+    hitpoints: 10,
+    class: &LAND,
+    flags: UnitFlags::empty(),
+    requirement: Some(&BRONZE_WORKING),
+    build_cost: 20,
+};
+
+pub static ARCHERS: UnitType = UnitType {
+    name: "Archers",
+    attack: 3,
+    defense: 2,
+    moves: 1,
+    hitpoints: 10,
+    class: &LAND,
+    flags: UnitFlags::empty(),
+    requirement: Some(&WARRIOR_CODE),
+    build_cost: 30,
+};
+
+pub static HORSEMEN: UnitType = UnitType {
+    name: "Horsemen",
+    attack: 2,
+    defense: 1,
+    moves: 2,
+    hitpoints: 10,
+    class: &LAND,
+    flags: UnitFlags::empty(),
+    requirement: Some(&HORSEBACK_RIDING),
+    build_cost: 20,
+};
+
+pub static TRIREME: UnitType = UnitType {
+    name: "Trireme",
+    attack: 1,
+    defense: 1,
+    moves: 3,
+    hitpoints: 10,
+    class: &SEA,
+    flags: UnitFlags::empty(),
+    requirement: Some(&MAP_MAKING),
+    build_cost: 40,
+};
+
+/// The leader of a civilization. In FreeCiv, players lose the game when they
+/// lose this unit.
+///
+/// TODO: Players do not lose the game yet when their leader dies.
+pub static LEADER: UnitType = UnitType {
+    name: "Leader",
+    attack: 0,
+    defense: 2,
+    moves: 2,
+    hitpoints: 20,
+    class: &LAND,
+    flags: UnitFlags::IGZOC.union(UnitFlags::NON_MILITARY),
+    requirement: None,
+    build_cost: 10,
+};
+
+pub static DIPLOMAT: UnitType = UnitType {
+    name: "Diplomat",
+    attack: 0,
+    defense: 1,
+    moves: 2,
+    hitpoints: 10,
+    class: &LAND,
+    flags: UnitFlags::IGZOC.union(UnitFlags::NON_MILITARY),
+    requirement: Some(&WRITING),
+    build_cost: 30,
+};
+
+pub static ALL_UNIT_TYPES: &[&UnitType] = &[
+    &SETTLERS, &WORKERS, &EXPLORER, &WARRIORS, &PHALANX, &ARCHERS, &HORSEMEN, &TRIREME, &LEADER,
+    &DIPLOMAT,
+];
@@ -0,0 +1,187 @@
+use crate::tiles::Terrain;
+
+/// Colatitude at the equator. The poles have a colatitude of 0.
+const MAX_COLATITUDE: u32 = 1000;
+
+/// Average temperature of the planet, mirroring the FreeCiv server's
+/// `temperature` setting.
+///
+/// Colder planets have large polar zones with glaciers and tundra, hotter
+/// planets have large tropical zones with deserts and jungles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Temperature {
+    /// A cold planet with small tropical zones.
+    Cold,
+    /// A planet with normal polar, cold, temperate and tropical zones.
+    Temperate,
+    /// A hot planet with little polar ice.
+    Hot,
+}
+
+impl Temperature {
+    /// The equivalent value of the FreeCiv `temperature` setting, between 0
+    /// and 100.
+    #[must_use]
+    pub const fn value(self) -> u32 {
+        match self {
+            Self::Cold => 30,
+            Self::Temperate => 50,
+            Self::Hot => 70,
+        }
+    }
+
+    /// Colatitude below which the climate is cold.
+    const fn cold_level(self) -> u32 {
+        (MAX_COLATITUDE * 60 * 7).saturating_sub(MAX_COLATITUDE * self.value() * 6) / 700
+    }
+
+    /// Colatitude above which the climate is tropical.
+    const fn tropical_level(self) -> u32 {
+        let level = (MAX_COLATITUDE * 143 * 7 - MAX_COLATITUDE * self.value() * 10) / 700;
+        let max = MAX_COLATITUDE * 9 / 10;
+
+        if level < max {
+            level
+        } else {
+            max
+        }
+    }
+}
+
+/// The climate zone of a row of the map, derived from its latitude and the
+/// planet's [`Temperature`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ClimateBand {
+    Frozen,
+    Cold,
+    Temperate,
+    Tropical,
+}
+
+impl ClimateBand {
+    /// Returns the climate band of row `y` on a map with `height` rows. The
+    /// poles are at the top and bottom rows, the equator is in the middle.
+    pub(crate) fn at(y: usize, height: usize, temperature: Temperature) -> Self {
+        let colatitude = if height <= 1 {
+            MAX_COLATITUDE
+        } else {
+            let distance_from_equator = (2 * y).abs_diff(height - 1);
+            let distance =
+                u32::try_from(distance_from_equator * MAX_COLATITUDE as usize / (height - 1))
+                    .unwrap_or(MAX_COLATITUDE);
+            MAX_COLATITUDE - distance.min(MAX_COLATITUDE)
+        };
+
+        let cold_level = temperature.cold_level();
+
+        if colatitude >= temperature.tropical_level() {
+            Self::Tropical
+        } else if colatitude >= cold_level {
+            Self::Temperate
+        } else if colatitude >= cold_level / 2 {
+            Self::Cold
+        } else {
+            Self::Frozen
+        }
+    }
+
+    /// Adjusts the terrain of a tile to fit this climate band. Water, hills
+    /// and mountains are left untouched, so polar hills do not turn into
+    /// glaciers, as in FreeCiv.
+    pub(crate) const fn apply(self, terrain: Terrain) -> Terrain {
+        if terrain.is_water() || matches!(terrain, Terrain::Hills | Terrain::Mountains) {
+            return terrain;
+        }
+
+        match (self, terrain) {
+            (Self::Frozen, _) => Terrain::Glacier,
+            (
+                Self::Cold,
+                Terrain::Plains | Terrain::Grassland | Terrain::Swamp | Terrain::Jungle,
+            ) => Terrain::Tundra,
+            (Self::Temperate, Terrain::Jungle) => Terrain::Forest,
+            (Self::Tropical, Terrain::Plains) => Terrain::Desert,
+            (Self::Tropical, Terrain::Grassland) => Terrain::Jungle,
+            _ => terrain,
+        }
+    }
+}
+
+#[test]
+fn test_temperature_band_fractions() {
+    fn count_rows(temperature: Temperature, band: ClimateBand) -> usize {
+        (0..100)
+            .filter(|&y| ClimateBand::at(y, 100, temperature) == band)
+            .count()
+    }
+
+    let polar = |temperature| {
+        count_rows(temperature, ClimateBand::Frozen) + count_rows(temperature, ClimateBand::Cold)
+    };
+    assert!(polar(Temperature::Cold) > polar(Temperature::Temperate));
+    assert!(polar(Temperature::Temperate) > polar(Temperature::Hot));
+
+    let tropical = |temperature| count_rows(temperature, ClimateBand::Tropical);
+    assert!(tropical(Temperature::Cold) < tropical(Temperature::Temperate));
+    assert!(tropical(Temperature::Temperate) < tropical(Temperature::Hot));
+
+    assert_eq!(
+        ClimateBand::at(0, 100, Temperature::Temperate),
+        ClimateBand::Frozen
+    );
+    assert_eq!(
+        ClimateBand::at(50, 100, Temperature::Temperate),
+        ClimateBand::Tropical
+    );
+}
+
+#[test]
+fn test_temperature_terrain_fractions() {
+    use super::{generate, Parameters};
+
+    // Percentages of the land that is glacier, tundra and desert, and whether
+    // there are hills in the frozen zone
+    let fractions = |temperature| {
+        let world = generate(Parameters {
+            seed: 7,
+            temperature,
+            ..Parameters::default()
+        })
+        .unwrap();
+        let land: Vec<_> = world
+            .grid
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| row.iter().map(move |tile| (y, tile.terrain)))
+            .filter(|(_, terrain)| !terrain.is_water())
+            .collect();
+        let percentage = |terrain| {
+            land.iter().filter(|&&(_, other)| other == terrain).count() * 100 / land.len()
+        };
+        let frozen_hills = land.iter().any(|&(y, terrain)| {
+            terrain == Terrain::Hills
+                && ClimateBand::at(y, world.height, temperature) == ClimateBand::Frozen
+        });
+
+        (
+            percentage(Terrain::Glacier),
+            percentage(Terrain::Tundra),
+            percentage(Terrain::Desert),
+            frozen_hills,
+        )
+    };
+
+    let (glacier, tundra, desert, frozen_hills) = fractions(Temperature::Cold);
+    assert!((10..20).contains(&glacier), "{glacier}% glacier");
+    assert!((5..15).contains(&tundra), "{tundra}% tundra");
+    assert!(desert < 5, "{desert}% desert");
+    assert!(
+        frozen_hills,
+        "hills in the frozen zone turned into glaciers"
+    );
+
+    // Hot planets have no polar zones at all
+    let (glacier, tundra, desert, _) = fractions(Temperature::Hot);
+    assert_eq!((glacier, tundra), (0, 0));
+    assert!((10..25).contains(&desert), "{desert}% desert");
+}
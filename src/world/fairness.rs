@@ -0,0 +1,183 @@
+use super::{SpecialWeights, World};
+use crate::tiles::{Flags, Special};
+
+/// What a player can reach from their start position, as counted by
+/// [`World::resource_access`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ResourceAccess {
+    /// Tiles with a special resource.
+    pub specials: usize,
+    /// Tiles with a river.
+    pub rivers: usize,
+    /// Land tiles next to water, where harbours and coastal cities are
+    /// possible.
+    pub coast: usize,
+}
+
+impl ResourceAccess {
+    /// A single number to compare the access of players. Specials count
+    /// twice, because they are worth more than a river or a coast.
+    #[must_use]
+    pub const fn score(&self) -> usize {
+        2 * self.specials + self.rivers + self.coast
+    }
+}
+
+/// How fairly resources are distributed between start positions, as returned
+/// by [`World::analyze_fairness`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FairnessReport {
+    /// The resource access of each start position, in the same order.
+    pub access: Vec<ResourceAccess>,
+}
+
+impl FairnessReport {
+    /// The difference between the best and the worst [`ResourceAccess::score`].
+    /// 0 means that every player has the same access.
+    #[must_use]
+    pub fn imbalance(&self) -> usize {
+        let scores = self.access.iter().map(ResourceAccess::score);
+        let max = scores.clone().max().unwrap_or(0);
+        let min = scores.min().unwrap_or(0);
+
+        max - min
+    }
+
+    /// Whether the imbalance is at most `tolerance`.
+    #[must_use]
+    pub fn is_balanced(&self, tolerance: usize) -> bool {
+        self.imbalance() <= tolerance
+    }
+}
+
+impl World {
+    /// Counts the resources within `radius` tiles of `x`, `y`.
+    #[must_use]
+    pub fn resource_access(&self, x: usize, y: usize, radius: usize) -> ResourceAccess {
+        self.tiles_within(x, y, radius)
+            .fold(ResourceAccess::default(), |mut access, tile| {
+                access.specials += usize::from(tile.special != Special::None);
+                access.rivers += usize::from(tile.flags.contains(Flags::HAS_RIVER));
+                access.coast += usize::from(tile.is_coastal());
+                access
+            })
+    }
+
+    /// Compares the resources around each start position, for example to
+    /// pick the fairest of several generated maps or to decide whether
+    /// [`World::equalize_specials`] is needed.
+    #[must_use]
+    pub fn analyze_fairness(&self, starts: &[(usize, usize)], radius: usize) -> FairnessReport {
+        FairnessReport {
+            access: starts
+                .iter()
+                .map(|&(x, y)| self.resource_access(x, y, radius))
+                .collect(),
+        }
+    }
+
+    /// Adds specials around the start positions with the fewest specials
+    /// within `radius`, until every start position has as many as the best
+    /// one or there is no room left. Returns the number of specials added.
+    ///
+    /// Specials are added to the tiles closest to the start position first,
+    /// choosing the special with the highest weight the terrain allows. Rivers
+    /// and coasts are part of the terrain and are not changed.
+    pub fn equalize_specials(
+        &mut self,
+        starts: &[(usize, usize)],
+        radius: usize,
+        weights: &SpecialWeights,
+    ) -> usize {
+        let specials_at =
+            |world: &Self, (x, y): (usize, usize)| world.resource_access(x, y, radius).specials;
+        let target = starts
+            .iter()
+            .map(|&start| specials_at(self, start))
+            .max()
+            .unwrap_or(0);
+        let mut added = 0;
+
+        for &(x, y) in starts {
+            let mut candidates: Vec<((usize, usize), Special)> = self
+                .tiles_within(x, y, radius)
+                .filter(|tile| tile.special == Special::None)
+                .filter_map(|tile| {
+                    let special = tile
+                        .terrain
+                        .allowed_specials()
+                        .iter()
+                        .copied()
+                        .filter(|&special| weights.get(special) > 0)
+                        .rev()
+                        .max_by_key(|&special| weights.get(special))?;
+                    Some((tile.position(), special))
+                })
+                .collect();
+            candidates.sort_by_key(|&(position, _)| self.distance(position, (x, y)));
+
+            for (position, special) in candidates {
+                if specials_at(self, (x, y)) >= target {
+                    break;
+                }
+                // SAFETY: The position was returned by `tiles_within`.
+                let mut tile =
+                    unsafe { self.tile_at_mut(position.0, position.1).unwrap_unchecked() };
+                tile.special = special;
+                added += 1;
+            }
+        }
+
+        added
+    }
+}
+
+#[test]
+fn test_fairness() {
+    use crate::tiles::{Terrain, Tile};
+
+    let mut world = World {
+        width: 12,
+        height: 5,
+        grid: vec![vec![Tile::new(Terrain::Grassland, Special::None, Flags::empty()); 12]; 5],
+        wrapping_x: false,
+        wrapping_y: false,
+    };
+    for x in 0..12 {
+        world.grid[4][x].terrain = Terrain::Ocean;
+    }
+    for (x, y) in [(1, 1), (2, 2), (3, 1)] {
+        world.grid[y][x].special = Special::Resources;
+    }
+    world.grid[2][9].flags = Flags::HAS_RIVER;
+    let starts = [(2, 2), (9, 2)];
+
+    let report = world.analyze_fairness(&starts, 2);
+    assert_eq!(
+        report.access,
+        [
+            ResourceAccess {
+                specials: 3,
+                rivers: 0,
+                coast: 5,
+            },
+            ResourceAccess {
+                specials: 0,
+                rivers: 1,
+                coast: 5,
+            },
+        ]
+    );
+    assert_eq!(report.imbalance(), 5);
+    assert!(!report.is_balanced(2));
+
+    let added = world.equalize_specials(&starts, 2, &SpecialWeights::default());
+    assert_eq!(added, 3);
+    let report = world.analyze_fairness(&starts, 2);
+    assert_eq!(report.access[1].specials, 3);
+    assert_eq!(report.imbalance(), 1);
+    // The closest tiles get the specials
+    assert_eq!(world.grid[2][9].special, Special::Resources);
+    assert_eq!(world.grid[1][8].special, Special::Resources);
+    assert_eq!(world.grid[3][9].special, Special::None);
+}
@@ -1,9 +1,15 @@
+use std::{f64::consts::TAU, fmt};
+
 use noise::{NoiseFn, OpenSimplex};
-use rand::{rngs::StdRng, Rng, SeedableRng};
 
-use super::World;
+use super::{
+    climate::{ClimateBand, Temperature},
+    specials::{place_specials, SpecialWeights},
+    World,
+};
 use crate::tiles::{Flags, Special, Terrain, Tile};
 
+#[derive(Clone)]
 pub struct Parameters {
     pub width: usize,
     pub height: usize,
@@ -12,6 +18,77 @@ pub struct Parameters {
     pub water_percentage: f32,
     pub seed: u32,
     pub land_distribution: LandDistribution,
+    /// Number of noise layers summed up to form the height map. More octaves
+    /// add finer detail to coastlines and elevation.
+    pub octaves: u32,
+    /// Frequency of the first octave. Lower values produce larger continents,
+    /// higher values produce many small islands.
+    pub base_frequency: f64,
+    /// Factor by which the frequency grows with each octave.
+    pub lacunarity: f64,
+    /// Factor by which the amplitude shrinks with each octave.
+    pub persistence: f64,
+    /// Normalized elevation below which tiles become ocean. If this yields
+    /// less water than `water_percentage`, the sea level is raised until
+    /// enough tiles are flooded, but never above `mountain_level`.
+    pub sea_level: f64,
+    /// Normalized elevation above which tiles become mountains. Lowering this
+    /// makes the map steeper, similar to FreeCiv's `steepness` setting.
+    pub mountain_level: f64,
+    /// Average temperature of the planet, which decides how much of the land
+    /// becomes glacier and tundra or desert and jungle.
+    pub temperature: Temperature,
+    /// Per mille of the tiles that get a special resource, if their terrain
+    /// allows one, like FreeCiv's `specials` server setting.
+    pub special_frequency: u32,
+    /// How likely each special is compared to the others on the same terrain.
+    pub special_weights: SpecialWeights,
+}
+
+impl Parameters {
+    /// Checks that all parameters are within their allowed ranges.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first [`ParametersError`] encountered.
+    pub fn validate(&self) -> Result<(), ParametersError> {
+        if self.width == 0 || self.height == 0 {
+            return Err(ParametersError::EmptyMap);
+        }
+
+        if !(0.0..=1.0).contains(&self.water_percentage) {
+            return Err(ParametersError::WaterPercentageOutOfRange);
+        }
+
+        if self.octaves == 0 {
+            return Err(ParametersError::NoOctaves);
+        }
+
+        if !self.base_frequency.is_finite() || self.base_frequency <= 0.0 {
+            return Err(ParametersError::InvalidBaseFrequency);
+        }
+
+        if !self.lacunarity.is_finite() || self.lacunarity < 1.0 {
+            return Err(ParametersError::InvalidLacunarity);
+        }
+
+        if !self.persistence.is_finite() || self.persistence <= 0.0 || self.persistence > 1.0 {
+            return Err(ParametersError::InvalidPersistence);
+        }
+
+        if self.special_frequency > 1000 {
+            return Err(ParametersError::SpecialFrequencyOutOfRange);
+        }
+
+        if !(self.sea_level >= 0.0
+            && self.sea_level < self.mountain_level
+            && self.mountain_level <= 1.0)
+        {
+            return Err(ParametersError::InvalidElevationLevels);
+        }
+
+        Ok(())
+    }
 }
 
 impl Default for Parameters {
@@ -24,62 +101,208 @@ impl Default for Parameters {
             water_percentage: 0.6,
             seed: 0,
             land_distribution: LandDistribution::Spread,
+            octaves: 5,
+            base_frequency: 1.0,
+            lacunarity: 2.0,
+            persistence: 0.5,
+            sea_level: 0.1,
+            mountain_level: 0.8,
+            temperature: Temperature::Temperate,
+            special_frequency: 250,
+            special_weights: SpecialWeights::default(),
         }
     }
 }
 
+/// The reason why [`Parameters`] were rejected by [`Parameters::validate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParametersError {
+    /// Width or height is zero.
+    EmptyMap,
+    /// The water percentage is not between 0 and 1.
+    WaterPercentageOutOfRange,
+    /// At least one octave is required to generate a height map.
+    NoOctaves,
+    /// The base frequency must be finite and positive.
+    InvalidBaseFrequency,
+    /// The lacunarity must be finite and at least 1.
+    InvalidLacunarity,
+    /// The persistence must be finite and in `(0, 1]`.
+    InvalidPersistence,
+    /// The levels must satisfy `0 <= sea_level < mountain_level <= 1`.
+    InvalidElevationLevels,
+    /// The special frequency is more than 1000 per mille.
+    SpecialFrequencyOutOfRange,
+}
+
+impl fmt::Display for ParametersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::EmptyMap => "map width and height must be non-zero",
+            Self::WaterPercentageOutOfRange => "water percentage must be between 0 and 1",
+            Self::NoOctaves => "at least one octave is required",
+            Self::InvalidBaseFrequency => "base frequency must be finite and positive",
+            Self::InvalidLacunarity => "lacunarity must be finite and at least 1",
+            Self::InvalidPersistence => "persistence must be finite and in (0, 1]",
+            Self::InvalidElevationLevels => {
+                "sea level and mountain level must satisfy 0 <= sea_level < mountain_level <= 1"
+            }
+            Self::SpecialFrequencyOutOfRange => "special frequency must be at most 1000 per mille",
+        })
+    }
+}
+
+impl std::error::Error for ParametersError {}
+
+#[derive(Clone, Copy)]
 pub enum LandDistribution {
     Spread,
     Continguous,
 }
 
-#[must_use]
-pub fn generate(params: Parameters) -> World {
-    let mut rng = StdRng::from_entropy();
+/// Terrain types for land between the sea level and the mountain level, from
+/// lowest to highest elevation.
+const LAND_BANDS: [Terrain; 6] = [
+    Terrain::Plains,
+    Terrain::Grassland,
+    Terrain::Hills,
+    Terrain::Forest,
+    Terrain::Swamp,
+    Terrain::Jungle,
+];
+
+/// Converts a map coordinate or size to `f64`. Maps are far smaller than
+/// 2^52 tiles, so the conversion is exact.
+#[allow(clippy::cast_precision_loss)]
+pub(crate) const fn to_f64(value: usize) -> f64 {
+    value as f64
+}
+
+/// Converts a non-negative value below the size of the map to an index,
+/// truncating the fraction. Negative values become 0.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub(crate) const fn to_index(value: f64) -> usize {
+    value as usize
+}
+
+/// Samples the noise for the tile at `x`, `y` scaled by `frequency`.
+///
+/// Wrapping axes are mapped onto a circle in an extra noise dimension, so that
+/// the noise is continuous across the seam of the map. The circle's
+/// circumference matches the axis length, so the scale of the noise is the
+/// same as on non-wrapping maps.
+pub(crate) fn sample_noise<N>(
+    noise: &N,
+    params: &Parameters,
+    x: usize,
+    y: usize,
+    frequency: f64,
+) -> f64
+where
+    N: NoiseFn<f64, 2> + NoiseFn<f64, 3> + NoiseFn<f64, 4>,
+{
+    let linear = |coordinate: usize| to_f64(coordinate) * frequency;
+    let circular = |coordinate: usize, length: usize| {
+        let angle = TAU * to_f64(coordinate) / to_f64(length);
+        let radius = to_f64(length) * frequency / TAU;
+        (radius * angle.cos(), radius * angle.sin())
+    };
+
+    match (params.wrapping_x, params.wrapping_y) {
+        (false, false) => noise.get([linear(x), linear(y)]),
+        (true, false) => {
+            let (x1, x2) = circular(x, params.width);
+            noise.get([x1, x2, linear(y)])
+        }
+        (false, true) => {
+            let (y1, y2) = circular(y, params.height);
+            noise.get([linear(x), y1, y2])
+        }
+        (true, true) => {
+            let (x1, x2) = circular(x, params.width);
+            let (y1, y2) = circular(y, params.height);
+            noise.get([x1, x2, y1, y2])
+        }
+    }
+}
+
+/// Generates a new world from the given parameters.
+///
+/// # Errors
+///
+/// Returns a [`ParametersError`] if the parameters are invalid.
+pub fn generate(params: Parameters) -> Result<World, ParametersError> {
+    params.validate()?;
+
+    let height_map = fractal_height_map(&params);
+
+    Ok(world_from_height_map(&params, height_map))
+}
+
+/// Sums up octaves of noise into a height map, indexed by `[y][x]`.
+pub(crate) fn fractal_height_map(params: &Parameters) -> Vec<Vec<f64>> {
     let noise = OpenSimplex::new(params.seed);
 
-    // Generate height map
     let mut height_map = vec![vec![0.0; params.width]; params.height];
-    for octave in 0..5 {
-        let frequency = 2.0_f64.powi(octave as i32);
-        let amplitude = 0.5_f64.powi(octave as i32);
+    let mut frequency = params.base_frequency;
+    let mut amplitude = 1.0;
+    for _ in 0..params.octaves {
         for y in 0..params.height {
             for x in 0..params.width {
-                height_map[y][x] +=
-                    noise.get([(x as f64) * frequency, (y as f64) * frequency]) * amplitude;
+                height_map[y][x] += sample_noise(&noise, params, x, y, frequency) * amplitude;
             }
         }
+        frequency *= params.lacunarity;
+        amplitude *= params.persistence;
     }
 
-    // Normalize height map
+    height_map
+}
+
+/// Scales all heights of a height map to values between 0 and 1.
+pub(crate) fn normalize(height_map: &mut [Vec<f64>]) {
     let mut min_height = f64::INFINITY;
     let mut max_height = f64::NEG_INFINITY;
-    for y in 0..params.height {
-        for x in 0..params.width {
-            min_height = min_height.min(height_map[y][x]);
-            max_height = max_height.max(height_map[y][x]);
-        }
-    }
-    for y in 0..params.height {
-        for x in 0..params.width {
-            height_map[y][x] = (height_map[y][x] - min_height) / (max_height - min_height);
-        }
+    for &height in height_map.iter().flatten() {
+        min_height = min_height.min(height);
+        max_height = max_height.max(height);
     }
 
-    // Add islands
-    let mut island_count = 0;
-    let total_count = params.width * params.height;
-    let island_target = ((1.0 - params.water_percentage) * total_count as f32).round() as usize;
-    while island_count < island_target {
-        let x = rng.gen_range(0..params.width);
-        let y = rng.gen_range(0..params.height);
-        if height_map[y][x] > 0.4 && height_map[y][x] < 0.8 {
-            height_map[y][x] = 0.0;
-            island_count += 1;
-        }
+    // Completely flat maps end up at height 0 instead of dividing by zero.
+    let range = (max_height - min_height).max(f64::MIN_POSITIVE);
+    for height in height_map.iter_mut().flatten() {
+        *height = (*height - min_height) / range;
     }
+}
+
+/// Turns a height map, indexed by `[y][x]`, into a world. The heights decide
+/// where the oceans, land and mountains are, then features and climate zones
+/// are added.
+///
+/// The parameters must have been validated before.
+pub(crate) fn world_from_height_map(params: &Parameters, mut height_map: Vec<Vec<f64>>) -> World {
+    let noise = OpenSimplex::new(params.seed);
+
+    normalize(&mut height_map);
 
-    // Add terrain types
+    // Raise the sea level if needed so that at least the requested percentage of
+    // the map is covered by water. Mountains are never flooded.
+    let sea_level = {
+        let mut heights: Vec<f64> = height_map.iter().flatten().copied().collect();
+        heights.sort_unstable_by(f64::total_cmp);
+        let water_target =
+            to_index((f64::from(params.water_percentage) * to_f64(heights.len())).round());
+        let required_level = heights
+            .get(water_target)
+            .copied()
+            .unwrap_or(params.mountain_level);
+        params
+            .sea_level
+            .max(required_level.min(params.mountain_level))
+    };
+
+    // Add terrain types. Land between the sea level and the mountain level is
+    // split into equally sized elevation bands.
     let mut world =
         vec![
             vec![Tile::new(Terrain::Ocean, Special::None, Flags::empty()); params.width];
@@ -87,25 +310,15 @@ pub fn generate(params: Parameters) -> World {
         ];
     for y in 0..params.height {
         for x in 0..params.width {
-            let feature_value = height_map[y][x];
-            let terrain_type = if feature_value < 0.1 {
+            let elevation = height_map[y][x];
+            let terrain_type = if elevation < sea_level {
                 Terrain::Ocean
-            } else if feature_value < 0.2 {
-                Terrain::Plains
-            } else if feature_value < 0.3 {
-                Terrain::Grassland
-            } else if feature_value < 0.4 {
-                Terrain::Hills
-            } else if feature_value < 0.5 {
-                Terrain::Forest
-            } else if feature_value < 0.6 {
-                Terrain::Swamp
-            } else if feature_value < 0.7 {
-                Terrain::Jungle
-            } else if feature_value < 0.8 {
+            } else if elevation >= params.mountain_level {
                 Terrain::Mountains
             } else {
-                Terrain::Desert
+                let relative = (elevation - sea_level) / (params.mountain_level - sea_level);
+                let band = to_index(relative * to_f64(LAND_BANDS.len()));
+                LAND_BANDS[band.min(LAND_BANDS.len() - 1)]
             };
             world[y][x].terrain = terrain_type;
         }
@@ -170,8 +383,7 @@ pub fn generate(params: Parameters) -> World {
             let amplitude = 0.5_f64.powi(octave as i32);
             for y in 0..params.height {
                 for x in 0..params.width {
-                    feature_map[y][x] +=
-                        noise.get([(x as f64) * frequency, (y as f64) * frequency]) * amplitude;
+                    feature_map[y][x] += sample_noise(&noise, params, x, y, frequency) * amplitude;
                 }
             }
         }
@@ -183,19 +395,23 @@ pub fn generate(params: Parameters) -> World {
             let terrain_type = world[y][x].terrain;
             let feature_value = feature_map[y][x];
             match terrain_type {
-                Terrain::Ocean | Terrain::Swamp => {
+                // Water is never turned into land here, to keep the water
+                // percentage guarantee intact.
+                Terrain::Swamp => {
                     if feature_value > 0.3 {
-                        world[x][y].terrain = Terrain::Forest;
+                        world[y][x].terrain = Terrain::Forest;
                     }
                 }
                 Terrain::Desert | Terrain::Plains | Terrain::Grassland => {
                     if feature_value > 0.5 {
-                        world[x][y].terrain = Terrain::Forest;
+                        world[y][x].terrain = Terrain::Forest;
                     }
                 }
-                Terrain::Hills | Terrain::Jungle | Terrain::Mountains => {
+                // Mountains stay, so that `mountain_level` alone decides how
+                // many there are.
+                Terrain::Hills | Terrain::Jungle => {
                     if feature_value > 0.4 {
-                        world[x][y].terrain = Terrain::Forest;
+                        world[y][x].terrain = Terrain::Forest;
                     }
                 }
                 Terrain::Forest => {
@@ -223,13 +439,151 @@ pub fn generate(params: Parameters) -> World {
         }
     }
 
-    let world = World {
+    // Apply climate zones based on latitude
+    for (y, row) in world.iter_mut().enumerate() {
+        let band = ClimateBand::at(y, params.height, params.temperature);
+        for tile in row {
+            tile.terrain = band.apply(tile.terrain);
+        }
+    }
+
+    let mut world = World {
         width: params.width,
         height: params.height,
         grid: world,
         wrapping_x: params.wrapping_x,
         wrapping_y: params.wrapping_y,
     };
+    place_specials(&mut world, params);
 
     world
 }
+
+#[test]
+fn test_parameters_validation() {
+    assert_eq!(Parameters::default().validate(), Ok(()));
+
+    let params = Parameters {
+        octaves: 0,
+        ..Parameters::default()
+    };
+    assert_eq!(params.validate(), Err(ParametersError::NoOctaves));
+
+    let params = Parameters {
+        base_frequency: f64::NAN,
+        ..Parameters::default()
+    };
+    assert_eq!(
+        params.validate(),
+        Err(ParametersError::InvalidBaseFrequency)
+    );
+
+    let params = Parameters {
+        lacunarity: 0.5,
+        ..Parameters::default()
+    };
+    assert_eq!(params.validate(), Err(ParametersError::InvalidLacunarity));
+
+    let params = Parameters {
+        persistence: 1.5,
+        ..Parameters::default()
+    };
+    assert_eq!(params.validate(), Err(ParametersError::InvalidPersistence));
+
+    let params = Parameters {
+        sea_level: 0.9,
+        mountain_level: 0.5,
+        ..Parameters::default()
+    };
+    assert_eq!(
+        params.validate(),
+        Err(ParametersError::InvalidElevationLevels)
+    );
+
+    let params = Parameters {
+        special_frequency: 1001,
+        ..Parameters::default()
+    };
+    assert_eq!(
+        params.validate(),
+        Err(ParametersError::SpecialFrequencyOutOfRange)
+    );
+}
+
+#[test]
+fn test_wrapping_noise_is_seamless() {
+    let noise = OpenSimplex::new(0);
+    let params = Parameters {
+        width: 40,
+        height: 30,
+        wrapping_x: true,
+        wrapping_y: true,
+        ..Parameters::default()
+    };
+
+    for frequency in [0.05, 0.3, 1.0] {
+        for y in 0..params.height {
+            let seam = sample_noise(&noise, &params, params.width, y, frequency);
+            let start = sample_noise(&noise, &params, 0, y, frequency);
+            assert!((seam - start).abs() < 1e-9);
+        }
+        for x in 0..params.width {
+            let seam = sample_noise(&noise, &params, x, params.height, frequency);
+            let start = sample_noise(&noise, &params, x, 0, frequency);
+            assert!((seam - start).abs() < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn test_water_percentage_guarantee() {
+    let params = Parameters {
+        width: 40,
+        height: 40,
+        water_percentage: 0.7,
+        sea_level: 0.05,
+        mountain_level: 0.95,
+        ..Parameters::default()
+    };
+    let world = generate(params).unwrap();
+    let water = world
+        .grid
+        .iter()
+        .flatten()
+        .filter(|t| t.terrain.is_water())
+        .count();
+    assert!(water * 10 >= 7 * 40 * 40);
+}
+
+#[test]
+fn test_mountain_level() {
+    let (width, height) = (40, 40);
+    // Every height occurs once, so the fraction of tiles at or above a level
+    // is known exactly.
+    let height_map: Vec<Vec<f64>> = (0..height)
+        .map(|y| (0..width).map(|x| to_f64(y * width + x)).collect())
+        .collect();
+    let max = to_f64(width * height - 1);
+
+    for mountain_level in [0.6, 0.8, 0.95] {
+        let params = Parameters {
+            width,
+            height,
+            mountain_level,
+            ..Parameters::default()
+        };
+        let world = world_from_height_map(&params, height_map.clone());
+        let mountains = world
+            .grid
+            .iter()
+            .flatten()
+            .filter(|tile| tile.terrain == Terrain::Mountains)
+            .count();
+        let expected = height_map
+            .iter()
+            .flatten()
+            .filter(|&&elevation| elevation / max >= mountain_level)
+            .count();
+        assert_eq!(mountains, expected, "mountain level {mountain_level}");
+    }
+}
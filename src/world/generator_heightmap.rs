@@ -0,0 +1,31 @@
+use std::path::Path;
+
+use image::{imageops, ImageResult};
+
+use super::{generator::world_from_height_map, Parameters, World};
+
+/// Generates a world from a grayscale image, where brighter pixels are higher.
+/// The image is scaled to the size of the map.
+///
+/// The parameters must have been validated before.
+///
+/// # Errors
+///
+/// Returns an error if the image cannot be read.
+pub(crate) fn generate(params: &Parameters, path: &Path) -> ImageResult<World> {
+    let image = image::open(path)?.into_luma16();
+    // Maps are far smaller than `u32::MAX` tiles in each direction.
+    let width = u32::try_from(params.width).unwrap_or(u32::MAX);
+    let height = u32::try_from(params.height).unwrap_or(u32::MAX);
+    let image = imageops::resize(&image, width, height, imageops::FilterType::Triangle);
+
+    let height_map = image
+        .rows()
+        .map(|row| {
+            row.map(|pixel| f64::from(pixel.0[0]) / f64::from(u16::MAX))
+                .collect()
+        })
+        .collect();
+
+    Ok(world_from_height_map(params, height_map))
+}
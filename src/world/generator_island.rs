@@ -0,0 +1,31 @@
+use super::{
+    generator::{fractal_height_map, normalize, to_f64, world_from_height_map},
+    Parameters, World,
+};
+
+/// Generates a world with a single large island in the middle of the map.
+///
+/// The fractal height map is lowered towards the edges of the map, so that
+/// land gathers around the center. Higher values of `falloff` keep more of the
+/// island's height near the coasts and make the island larger.
+///
+/// The parameters must have been validated before.
+pub(crate) fn generate(params: &Parameters, falloff: f64) -> World {
+    let mut height_map = fractal_height_map(params);
+    normalize(&mut height_map);
+
+    let center_x = (to_f64(params.width) - 1.0) / 2.0;
+    let center_y = (to_f64(params.height) - 1.0) / 2.0;
+    for (y, row) in height_map.iter_mut().enumerate() {
+        for (x, height) in row.iter_mut().enumerate() {
+            // Distance from the center, 1 at the middle of each edge
+            let dx = (to_f64(x) - center_x) / center_x.max(1.0);
+            let dy = (to_f64(y) - center_y) / center_y.max(1.0);
+            let distance = dx.hypot(dy);
+
+            *height *= (1.0 - distance.powf(falloff)).max(0.0);
+        }
+    }
+
+    world_from_height_map(params, height_map)
+}
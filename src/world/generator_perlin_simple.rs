@@ -1,147 +1,84 @@
-use noise::{NoiseFn, Perlin, Seedable};
-use rand::{rngs::SmallRng, Rng, SeedableRng};
+use noise::Perlin;
 
-use super::World;
+use super::{
+    generator::{sample_noise, to_f64, to_index},
+    specials::place_specials,
+    Parameters, World,
+};
 use crate::tiles::{Flags, Special, Terrain, Tile};
 
-pub struct Parameters {
-    pub width: usize,
-    pub height: usize,
-    pub wrapping_x: bool,
-    pub wrapping_y: bool,
-    pub water_percentage: f32,
-    pub seed: u32,
-    pub land_distribution: LandDistribution,
-}
-
-impl Default for Parameters {
-    fn default() -> Self {
-        Self {
-            width: 64,
-            height: 64,
-            wrapping_x: true,
-            wrapping_y: false,
-            water_percentage: 0.05,
-            seed: 0,
-            land_distribution: LandDistribution::Spread,
-        }
-    }
-}
-
-pub enum LandDistribution {
-    Spread,
-    Continguous,
-}
-
-#[must_use]
-pub fn generate(params: Parameters) -> World {
-    let mut rng = SmallRng::from_entropy();
+/// Generates a world from a single layer of Perlin noise. `scale` is the
+/// number of noise periods across the width of the map.
+///
+/// The lowest tiles are flooded until the water percentage is reached, the
+/// remaining land is split into terrain types by elevation. Octaves, elevation
+/// levels and temperature are ignored.
+///
+/// The parameters must have been validated before.
+pub(crate) fn generate(params: &Parameters, scale: f64) -> World {
     let perlin = Perlin::new(params.seed);
-    let mut world =
-        vec![
-            vec![Tile::new(Terrain::Grassland, Special::None, Flags::empty()); params.width];
-            params.height
-        ];
-
-    println!("Generating height map");
+    let frequency = scale / to_f64(params.width);
 
     // Generate height map using Perlin noise
     let mut heights = vec![vec![0.0; params.width]; params.height];
-    let mut max_height = 0.0f32;
-    let mut min_height = 1.0f32;
-    for y in 0..params.height {
-        for x in 0..params.width {
-            let height_val = perlin.get([
-                (x as f64 / params.width as f64) * 4.0,
-                (y as f64 / params.height as f64) * 4.0,
-            ]);
-            heights[x][y] = height_val as f32;
-            max_height = max_height.max(height_val as f32);
-            min_height = min_height.min(height_val as f32);
+    for (y, row) in heights.iter_mut().enumerate() {
+        for (x, height) in row.iter_mut().enumerate() {
+            *height = sample_noise(&perlin, params, x, y, frequency);
         }
     }
 
-    println!("Normalizing height map");
-
-    // Normalize height map to values between 0 and 1
-    for y in 0..params.height {
-        for x in 0..params.width {
-            heights[x][y] = (heights[x][y] - min_height) / (max_height - min_height);
-        }
-    }
-
-    println!("Placing water tiles");
-
-    // Set water tiles
-    let mut water_threshold = rng.gen_range(0.0..1.0 - params.water_percentage);
-    let mut water_count = 0;
-    for y in 0..params.height {
-        for x in 0..params.width {
-            if heights[x][y] < water_threshold {
-                world[x][y].terrain = Terrain::Ocean;
-                water_count += 1;
-            }
-        }
-    }
-
-    println!("Adjusting water threshold");
-
-    // TODO: This it endless for tiny water percentages
-    // Adjust water threshold if needed to reach desired water percentage
-    if water_count as f32 / (params.width * params.height) as f32 != params.water_percentage {
-        let mut diff = (water_count as f32 / (params.width * params.height) as f32
-            - params.water_percentage)
-            .abs();
-
-        while diff.abs() > 0.001 {
-            if diff > 0.0 {
-                water_threshold += 0.0001;
-            } else {
-                water_threshold -= 0.0001;
-            }
-            water_count = 0;
-            for x in 0..params.width {
-                for y in 0..params.height {
-                    if heights[x][y] < water_threshold {
-                        world[x][y].terrain = Terrain::Ocean;
-                        water_count += 1;
-                    }
-                }
-            }
-            diff = (water_count as f32 / (params.width * params.height) as f32
-                - params.water_percentage)
-                .abs();
-        }
-    }
-
-    println!("Generating other terrain");
-
-    // Generate other terrain types based on height map
-    for y in 0..params.height {
-        for x in 0..params.width {
-            if world[x][y].terrain == Terrain::Grassland {
-                if heights[x][y] < 0.3 {
-                    world[x][y].terrain = Terrain::Swamp;
-                } else if heights[x][y] < 0.4 {
-                    world[x][y].terrain = Terrain::Forest;
-                } else if heights[x][y] < 0.6 {
-                    world[x][y].terrain = Terrain::Hills;
-                } else if heights[x][y] < 0.8 {
-                    world[x][y].terrain = Terrain::Mountains;
-                } else {
-                    world[x][y].terrain = Terrain::Glacier;
-                }
-            }
-        }
-    }
-
-    let world = World {
+    // Find the water threshold by sorting all heights
+    let water_threshold = {
+        let mut sorted: Vec<f64> = heights.iter().flatten().copied().collect();
+        sorted.sort_unstable_by(f64::total_cmp);
+        let water_count =
+            to_index((f64::from(params.water_percentage) * to_f64(sorted.len())).round());
+        sorted.get(water_count).copied().unwrap_or(f64::INFINITY)
+    };
+    let max_height = heights
+        .iter()
+        .flatten()
+        .copied()
+        .fold(f64::NEG_INFINITY, f64::max);
+
+    // Generate terrain types based on the elevation above the water threshold
+    let grid = heights
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|&height| {
+                    let terrain = if height < water_threshold {
+                        Terrain::Ocean
+                    } else {
+                        let relative = (height - water_threshold)
+                            / (max_height - water_threshold).max(f64::MIN_POSITIVE);
+                        if relative < 0.3 {
+                            Terrain::Swamp
+                        } else if relative < 0.4 {
+                            Terrain::Forest
+                        } else if relative < 0.6 {
+                            Terrain::Hills
+                        } else if relative < 0.8 {
+                            Terrain::Mountains
+                        } else {
+                            Terrain::Glacier
+                        }
+                    };
+
+                    Tile::new(terrain, Special::None, Flags::empty())
+                })
+                .collect()
+        })
+        .collect();
+
+    let mut world = World {
         width: params.width,
         height: params.height,
-        grid: world,
+        grid,
         wrapping_x: params.wrapping_x,
         wrapping_y: params.wrapping_y,
     };
+    place_specials(&mut world, params);
 
     world
 }
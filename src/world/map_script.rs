@@ -0,0 +1,379 @@
+use std::{collections::BTreeMap, fmt, path::PathBuf, str::FromStr};
+
+use super::{
+    generator, generator_heightmap, generator_island, generator_perlin_simple, Parameters,
+    ParametersError, World,
+};
+
+/// Script-specific options, keyed by option name. Values are parsed by the
+/// script according to its [`OptionSpec`]s.
+pub type ScriptOptions = BTreeMap<String, String>;
+
+/// The type of value a script option expects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptionKind {
+    Integer,
+    Float,
+    Boolean,
+    Path,
+}
+
+impl OptionKind {
+    /// Whether a value can be parsed as this kind of option.
+    fn accepts(self, value: &str) -> bool {
+        match self {
+            Self::Integer => value.parse::<i64>().is_ok(),
+            Self::Float => value.parse::<f64>().is_ok_and(f64::is_finite),
+            Self::Boolean => value.parse::<bool>().is_ok(),
+            Self::Path => !value.is_empty(),
+        }
+    }
+}
+
+/// Describes an option a map script accepts in addition to the common
+/// [`Parameters`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OptionSpec {
+    pub name: &'static str,
+    pub kind: OptionKind,
+    /// Whether the script fails without this option.
+    pub required: bool,
+    pub description: &'static str,
+}
+
+/// The reason why a map script could not generate a world.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MapScriptError {
+    /// No script is registered under this name.
+    UnknownScript(String),
+    /// A script with this name is already registered.
+    DuplicateScript(String),
+    /// The script does not accept an option with this name.
+    UnknownOption(String),
+    /// A required option was not given.
+    MissingOption(&'static str),
+    /// The value of an option could not be parsed.
+    InvalidOption(String),
+    /// The common parameters are invalid.
+    Parameters(ParametersError),
+    /// The script failed for another reason.
+    Failed(String),
+}
+
+impl fmt::Display for MapScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownScript(name) => write!(f, "unknown map script `{name}`"),
+            Self::DuplicateScript(name) => write!(f, "map script `{name}` is already registered"),
+            Self::UnknownOption(name) => write!(f, "unknown option `{name}`"),
+            Self::MissingOption(name) => write!(f, "missing required option `{name}`"),
+            Self::InvalidOption(name) => write!(f, "invalid value for option `{name}`"),
+            Self::Parameters(error) => error.fmt(f),
+            Self::Failed(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for MapScriptError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Parameters(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParametersError> for MapScriptError {
+    fn from(error: ParametersError) -> Self {
+        Self::Parameters(error)
+    }
+}
+
+/// Parses the option `name`, returning `None` if it was not given.
+///
+/// # Errors
+///
+/// Returns [`MapScriptError::InvalidOption`] if the value cannot be parsed.
+pub fn parse_option<T: FromStr>(
+    options: &ScriptOptions,
+    name: &str,
+) -> Result<Option<T>, MapScriptError> {
+    options
+        .get(name)
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| MapScriptError::InvalidOption(name.to_owned()))
+        })
+        .transpose()
+}
+
+/// A map generator that can be selected by name.
+pub trait MapScript {
+    /// The name the script is registered under, such as `"fractal"`.
+    fn name(&self) -> &str;
+
+    /// The options this script accepts in addition to the common
+    /// [`Parameters`].
+    fn schema(&self) -> &[OptionSpec] {
+        &[]
+    }
+
+    /// Generates a new world.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MapScriptError`] if the parameters or options are invalid
+    /// or generation fails.
+    fn generate(
+        &self,
+        params: Parameters,
+        options: &ScriptOptions,
+    ) -> Result<World, MapScriptError>;
+}
+
+/// The default generator, a fractal height map of several noise octaves.
+pub struct FractalScript;
+
+impl MapScript for FractalScript {
+    fn name(&self) -> &'static str {
+        "fractal"
+    }
+
+    fn generate(&self, params: Parameters, _: &ScriptOptions) -> Result<World, MapScriptError> {
+        Ok(generator::generate(params)?)
+    }
+}
+
+/// A simple generator using a single layer of Perlin noise.
+pub struct PerlinSimpleScript;
+
+impl MapScript for PerlinSimpleScript {
+    fn name(&self) -> &'static str {
+        "perlin-simple"
+    }
+
+    fn schema(&self) -> &[OptionSpec] {
+        &[OptionSpec {
+            name: "scale",
+            kind: OptionKind::Float,
+            required: false,
+            description: "Number of noise periods across the map, 4 by default",
+        }]
+    }
+
+    fn generate(
+        &self,
+        params: Parameters,
+        options: &ScriptOptions,
+    ) -> Result<World, MapScriptError> {
+        params.validate()?;
+        let scale = parse_option(options, "scale")?.unwrap_or(4.0);
+
+        Ok(generator_perlin_simple::generate(&params, scale))
+    }
+}
+
+/// A generator that places a single large island in the middle of the map.
+pub struct IslandScript;
+
+impl MapScript for IslandScript {
+    fn name(&self) -> &'static str {
+        "island"
+    }
+
+    fn schema(&self) -> &[OptionSpec] {
+        &[OptionSpec {
+            name: "falloff",
+            kind: OptionKind::Float,
+            required: false,
+            description: "How sharply the land drops off towards the edges, 2 by default",
+        }]
+    }
+
+    fn generate(
+        &self,
+        params: Parameters,
+        options: &ScriptOptions,
+    ) -> Result<World, MapScriptError> {
+        params.validate()?;
+        let falloff = parse_option(options, "falloff")?.unwrap_or(2.0);
+
+        Ok(generator_island::generate(&params, falloff))
+    }
+}
+
+/// A generator that reads the height map from a grayscale PNG image.
+pub struct HeightmapScript;
+
+impl MapScript for HeightmapScript {
+    fn name(&self) -> &'static str {
+        "from-heightmap"
+    }
+
+    fn schema(&self) -> &[OptionSpec] {
+        &[OptionSpec {
+            name: "path",
+            kind: OptionKind::Path,
+            required: true,
+            description: "Grayscale PNG image, brighter pixels are higher",
+        }]
+    }
+
+    fn generate(
+        &self,
+        params: Parameters,
+        options: &ScriptOptions,
+    ) -> Result<World, MapScriptError> {
+        params.validate()?;
+        let path: PathBuf =
+            parse_option(options, "path")?.ok_or(MapScriptError::MissingOption("path"))?;
+
+        generator_heightmap::generate(&params, &path)
+            .map_err(|error| MapScriptError::Failed(error.to_string()))
+    }
+}
+
+/// A collection of map scripts, looked up by name.
+pub struct MapScripts {
+    scripts: Vec<Box<dyn MapScript>>,
+}
+
+impl MapScripts {
+    /// Creates a registry with all built-in map scripts.
+    #[must_use]
+    pub fn builtin() -> Self {
+        Self {
+            scripts: vec![
+                Box::new(FractalScript),
+                Box::new(PerlinSimpleScript),
+                Box::new(IslandScript),
+                Box::new(HeightmapScript),
+            ],
+        }
+    }
+
+    /// Adds a map script.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MapScriptError::DuplicateScript`] if a script with the same
+    /// name is already registered.
+    pub fn register(&mut self, script: Box<dyn MapScript>) -> Result<(), MapScriptError> {
+        if self.get(script.name()).is_some() {
+            return Err(MapScriptError::DuplicateScript(script.name().to_owned()));
+        }
+
+        self.scripts.push(script);
+
+        Ok(())
+    }
+
+    /// The script registered under `name`, if there is one.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&dyn MapScript> {
+        self.scripts
+            .iter()
+            .find(|script| script.name() == name)
+            .map(AsRef::as_ref)
+    }
+
+    /// The names of all registered scripts, in registration order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.scripts.iter().map(|script| script.name())
+    }
+
+    /// Generates a new world with the script registered under `name`. The
+    /// options are checked against the script's schema first.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MapScriptError`] if there is no such script, an option does
+    /// not match the schema or the script fails.
+    pub fn generate(
+        &self,
+        name: &str,
+        params: Parameters,
+        options: &ScriptOptions,
+    ) -> Result<World, MapScriptError> {
+        let script = self
+            .get(name)
+            .ok_or_else(|| MapScriptError::UnknownScript(name.to_owned()))?;
+        let schema = script.schema();
+
+        for (option, value) in options {
+            let spec = schema
+                .iter()
+                .find(|spec| spec.name == option)
+                .ok_or_else(|| MapScriptError::UnknownOption(option.clone()))?;
+
+            if !spec.kind.accepts(value) {
+                return Err(MapScriptError::InvalidOption(option.clone()));
+            }
+        }
+
+        if let Some(spec) = schema
+            .iter()
+            .find(|spec| spec.required && !options.contains_key(spec.name))
+        {
+            return Err(MapScriptError::MissingOption(spec.name));
+        }
+
+        script.generate(params, options)
+    }
+}
+
+#[test]
+fn test_builtin_map_scripts() {
+    let scripts = MapScripts::builtin();
+    assert_eq!(
+        scripts.names().collect::<Vec<_>>(),
+        ["fractal", "perlin-simple", "island", "from-heightmap"]
+    );
+
+    let small = || Parameters {
+        width: 32,
+        height: 32,
+        base_frequency: 0.1,
+        ..Parameters::default()
+    };
+    for name in ["fractal", "perlin-simple", "island"] {
+        let world = scripts
+            .generate(name, small(), &ScriptOptions::new())
+            .unwrap();
+        assert_eq!((world.width(), world.height()), (32, 32));
+    }
+
+    // The island script keeps the corners of the map under water
+    let world = scripts
+        .generate("island", small(), &ScriptOptions::new())
+        .unwrap();
+    assert!(world.tile_at(0, 0).unwrap().terrain.is_water());
+    assert!(world.tile_at(31, 31).unwrap().terrain.is_water());
+
+    assert_eq!(
+        scripts
+            .generate("from-heightmap", small(), &ScriptOptions::new())
+            .err(),
+        Some(MapScriptError::MissingOption("path"))
+    );
+    let options = ScriptOptions::from([(String::from("scale"), String::from("big"))]);
+    assert_eq!(
+        scripts.generate("perlin-simple", small(), &options).err(),
+        Some(MapScriptError::InvalidOption(String::from("scale")))
+    );
+    assert_eq!(
+        scripts.generate("fractal", small(), &options).err(),
+        Some(MapScriptError::UnknownOption(String::from("scale")))
+    );
+    assert_eq!(
+        scripts.generate("voronoi", small(), &options).err(),
+        Some(MapScriptError::UnknownScript(String::from("voronoi")))
+    );
+
+    let mut scripts = scripts;
+    assert_eq!(
+        scripts.register(Box::new(FractalScript)),
+        Err(MapScriptError::DuplicateScript(String::from("fractal")))
+    );
+}
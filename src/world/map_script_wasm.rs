@@ -0,0 +1,340 @@
+//! Map scripts compiled to WebAssembly, loaded at runtime.
+//!
+//! A plugin is a WebAssembly module without imports that exports its linear
+//! memory as `memory` and a function
+//!
+//! ```text
+//! freeciv_generate(width: i32, height: i32, seed: i32, flags: i32,
+//!                  water_percentage: f32, temperature: i32) -> i32
+//! ```
+//!
+//! Bit 0 of `flags` is set if the map wraps in x direction, bit 1 if it wraps
+//! in y direction. `temperature` is the value of FreeCiv's `temperature`
+//! setting, see [`Temperature::value`](super::Temperature::value). The other
+//! [`Parameters`] only apply to the built-in generators and are not passed.
+//!
+//! The function returns the address of `width * height` bytes in its memory,
+//! one per tile and row by row, with the terrain identifiers of FreeCiv's
+//! classic ruleset, such as `g` for grassland and a space for ocean. Negative
+//! addresses signal that generation failed. Plugins only generate terrain,
+//! specials are placed afterwards like for the built-in scripts and the tiles
+//! have no flags.
+//!
+//! Plugins may execute [`FUEL_PER_TILE`] WebAssembly instructions per tile of
+//! the map, roughly. Plugins that run longer, for example because they never
+//! return, fail with [`MapScriptError::Failed`]. Their memory may grow to
+//! [`MEMORY_PER_TILE`] bytes per tile, but at least [`MIN_MEMORY`]; beyond
+//! that, `memory.grow` fails.
+
+use std::{fmt, path::Path};
+
+use wasmtime::{
+    Config, Engine, ExternType, Instance, Module, Store, StoreLimits, StoreLimitsBuilder, Trap,
+};
+
+use super::{
+    specials::place_specials, MapScript, MapScriptError, Parameters, ScriptOptions, World,
+};
+use crate::tiles::{Flags, Special, Terrain, Tile};
+
+/// The function plugins export to generate a map.
+const GENERATE_EXPORT: &str = "freeciv_generate";
+/// The memory plugins export for the generated map.
+const MEMORY_EXPORT: &str = "memory";
+/// The fuel a plugin gets per tile of the map. Most instructions consume one
+/// unit of fuel.
+const FUEL_PER_TILE: u64 = 100_000;
+/// The memory in bytes a plugin may use per tile of the map.
+const MEMORY_PER_TILE: usize = 4096;
+/// The memory in bytes a plugin may use on any map, enough for the module's
+/// own data on small maps.
+const MIN_MEMORY: usize = 16 << 20;
+
+/// The reason why a plugin could not be loaded.
+#[derive(Debug)]
+pub enum PluginError {
+    /// The file could not be read or is not a valid WebAssembly module.
+    Invalid(String),
+    /// The module does not export the function or memory with this name.
+    MissingExport(&'static str),
+}
+
+impl fmt::Display for PluginError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid(message) => write!(f, "invalid plugin: {message}"),
+            Self::MissingExport(name) => write!(f, "plugin does not export `{name}`"),
+        }
+    }
+}
+
+impl std::error::Error for PluginError {}
+
+/// A map script loaded from a WebAssembly module. Plugins that run too long
+/// fail instead of blocking the program.
+pub struct WasmScript {
+    name: String,
+    engine: Engine,
+    module: Module,
+}
+
+impl WasmScript {
+    /// Loads a plugin from a `.wasm` file. The script is named after the file
+    /// without its extension.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PluginError`] if the file is not a valid plugin.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, PluginError> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path).map_err(|error| PluginError::Invalid(error.to_string()))?;
+        let name = path.file_stem().map_or_else(
+            || String::from("plugin"),
+            |stem| stem.to_string_lossy().into_owned(),
+        );
+
+        Self::from_bytes(name, &bytes)
+    }
+
+    /// Loads a plugin from the bytes of a WebAssembly module.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PluginError`] if the bytes are not a valid plugin.
+    pub fn from_bytes(name: String, bytes: &[u8]) -> Result<Self, PluginError> {
+        let mut config = Config::new();
+        config.consume_fuel(true);
+        let engine =
+            Engine::new(&config).map_err(|error| PluginError::Invalid(error.to_string()))?;
+        let module =
+            Module::new(&engine, bytes).map_err(|error| PluginError::Invalid(error.to_string()))?;
+
+        if !matches!(
+            module.get_export(GENERATE_EXPORT),
+            Some(ExternType::Func(_))
+        ) {
+            return Err(PluginError::MissingExport(GENERATE_EXPORT));
+        }
+        if !matches!(
+            module.get_export(MEMORY_EXPORT),
+            Some(ExternType::Memory(_))
+        ) {
+            return Err(PluginError::MissingExport(MEMORY_EXPORT));
+        }
+
+        Ok(Self {
+            name,
+            engine,
+            module,
+        })
+    }
+
+    /// Runs the plugin and returns its terrain bytes.
+    fn run(&self, params: &Parameters) -> Result<Vec<u8>, String> {
+        let len = params
+            .width
+            .checked_mul(params.height)
+            .ok_or("map too large")?;
+        let size = |value: usize| i32::try_from(value).map_err(|_| "map too large");
+        let flags = i32::from(params.wrapping_x) | i32::from(params.wrapping_y) << 1;
+        let args = (
+            size(params.width)?,
+            size(params.height)?,
+            // The seed is passed on bit for bit.
+            i32::from_ne_bytes(params.seed.to_ne_bytes()),
+            flags,
+            params.water_percentage,
+            i32::try_from(params.temperature.value()).map_err(|_| "invalid temperature")?,
+        );
+
+        let fuel = u64::try_from(len)
+            .unwrap_or(u64::MAX)
+            .saturating_mul(FUEL_PER_TILE);
+        let limits = StoreLimitsBuilder::new()
+            .memory_size(len.saturating_mul(MEMORY_PER_TILE).max(MIN_MEMORY))
+            .build();
+        let mut store = Store::new(&self.engine, limits);
+        store.limiter(|limits: &mut StoreLimits| limits);
+        store.set_fuel(fuel).map_err(|error| error.to_string())?;
+        let instance =
+            Instance::new(&mut store, &self.module, &[]).map_err(|error| error.to_string())?;
+        let generate = instance
+            .get_typed_func::<(i32, i32, i32, i32, f32, i32), i32>(&mut store, GENERATE_EXPORT)
+            .map_err(|error| error.to_string())?;
+        // SAFETY: The export was checked when the plugin was loaded.
+        let memory = unsafe {
+            instance
+                .get_memory(&mut store, MEMORY_EXPORT)
+                .unwrap_unchecked()
+        };
+
+        let address = generate.call(&mut store, args).map_err(|error| {
+            if error.downcast_ref::<Trap>() == Some(&Trap::OutOfFuel) {
+                String::from("the plugin ran out of fuel")
+            } else {
+                error.to_string()
+            }
+        })?;
+        let address = usize::try_from(address).map_err(|_| "the plugin failed")?;
+
+        address
+            .checked_add(len)
+            .and_then(|end| memory.data(&store).get(address..end))
+            .map(<[u8]>::to_vec)
+            .ok_or_else(|| String::from("the plugin returned an invalid address"))
+    }
+}
+
+impl MapScript for WasmScript {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn generate(&self, params: Parameters, _: &ScriptOptions) -> Result<World, MapScriptError> {
+        params.validate()?;
+        let terrain = self.run(&params).map_err(MapScriptError::Failed)?;
+
+        let tiles = terrain
+            .into_iter()
+            .map(|identifier| {
+                let identifier = char::from(identifier);
+                Terrain::from_identifier(identifier)
+                    .map(|terrain| Tile::new(terrain, Special::None, Flags::empty()))
+                    .ok_or_else(|| {
+                        MapScriptError::Failed(format!("unknown terrain `{identifier}`"))
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let grid = tiles.chunks(params.width).map(<[Tile]>::to_vec).collect();
+
+        let mut world = World {
+            width: params.width,
+            height: params.height,
+            grid,
+            wrapping_x: params.wrapping_x,
+            wrapping_y: params.wrapping_y,
+        };
+        place_specials(&mut world, &params);
+
+        Ok(world)
+    }
+}
+
+#[test]
+fn test_wasm_script() {
+    use crate::tiles::Terrain;
+
+    // Fills the left half of the map with grassland and the rest with ocean.
+    let plugin = r#"
+        (module
+          (memory (export "memory") 1)
+          (func (export "freeciv_generate")
+            (param $width i32) (param $height i32) (param $seed i32)
+            (param $flags i32) (param $water f32) (param $temperature i32)
+            (result i32)
+            (local $i i32)
+            (loop $tiles
+              (i32.store8
+                (local.get $i)
+                (select (i32.const 103) (i32.const 32)
+                  (i32.lt_u
+                    (i32.rem_u (local.get $i) (local.get $width))
+                    (i32.div_u (local.get $width) (i32.const 2)))))
+              (local.set $i (i32.add (local.get $i) (i32.const 1)))
+              (br_if $tiles
+                (i32.lt_u (local.get $i) (i32.mul (local.get $width) (local.get $height)))))
+            (i32.const 0)))
+    "#;
+    let script = WasmScript::from_bytes(String::from("halves"), plugin.as_bytes()).unwrap();
+    assert_eq!(script.name(), "halves");
+
+    let world = script
+        .generate(
+            Parameters {
+                width: 6,
+                height: 3,
+                ..Parameters::default()
+            },
+            &ScriptOptions::new(),
+        )
+        .unwrap();
+    assert_eq!((world.width(), world.height()), (6, 3));
+    assert_eq!(world.tile_at(2, 1).unwrap().terrain(), Terrain::Grassland);
+    assert_eq!(world.tile_at(3, 2).unwrap().terrain(), Terrain::Ocean);
+
+    let failing = r#"
+        (module
+          (memory (export "memory") 1)
+          (func (export "freeciv_generate")
+            (param i32 i32 i32 i32 f32 i32) (result i32)
+            (i32.const -1)))
+    "#;
+    let script = WasmScript::from_bytes(String::from("failing"), failing.as_bytes()).unwrap();
+    assert!(matches!(
+        script.generate(Parameters::default(), &ScriptOptions::new()),
+        Err(MapScriptError::Failed(_))
+    ));
+
+    let endless = r#"
+        (module
+          (memory (export "memory") 1)
+          (func (export "freeciv_generate")
+            (param i32 i32 i32 i32 f32 i32) (result i32)
+            (loop $forever (br $forever))
+            (i32.const 0)))
+    "#;
+    let script = WasmScript::from_bytes(String::from("endless"), endless.as_bytes()).unwrap();
+    assert!(matches!(
+        script.generate(
+            Parameters {
+                width: 4,
+                height: 4,
+                ..Parameters::default()
+            },
+            &ScriptOptions::new()
+        ),
+        Err(MapScriptError::Failed(message)) if message == "the plugin ran out of fuel"
+    ));
+
+    // Returns the largest possible address, far beyond the end of its memory.
+    let overflowing = r#"
+        (module
+          (memory (export "memory") 1)
+          (func (export "freeciv_generate")
+            (param i32 i32 i32 i32 f32 i32) (result i32)
+            (i32.const 2147483647)))
+    "#;
+    let script =
+        WasmScript::from_bytes(String::from("overflowing"), overflowing.as_bytes()).unwrap();
+    assert!(matches!(
+        script.generate(Parameters::default(), &ScriptOptions::new()),
+        Err(MapScriptError::Failed(message)) if message == "the plugin returned an invalid address"
+    ));
+
+    // Grows its memory by 256 MiB and returns the old size in pages as the
+    // address, or -1 if growing failed.
+    let growing = r#"
+        (module
+          (memory (export "memory") 1)
+          (func (export "freeciv_generate")
+            (param i32 i32 i32 i32 f32 i32) (result i32)
+            (memory.grow (i32.const 4096))))
+    "#;
+    let script = WasmScript::from_bytes(String::from("growing"), growing.as_bytes()).unwrap();
+    assert!(matches!(
+        script.generate(
+            Parameters {
+                width: 4,
+                height: 4,
+                ..Parameters::default()
+            },
+            &ScriptOptions::new()
+        ),
+        Err(MapScriptError::Failed(message)) if message == "the plugin failed"
+    ));
+
+    assert!(matches!(
+        WasmScript::from_bytes(String::from("empty"), b"(module)"),
+        Err(PluginError::MissingExport(GENERATE_EXPORT))
+    ));
+}
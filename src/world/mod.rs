@@ -1,6 +1,41 @@
+//! Generating, analyzing and rendering worlds.
+//!
+//! ```
+//! use freeciv_rs::world::{generate, Parameters};
+//!
+//! let world = generate(Parameters {
+//!     width: 20,
+//!     height: 10,
+//!     ..Parameters::default()
+//! })
+//! .unwrap();
+//! assert_eq!((world.width(), world.height()), (20, 10));
+//! ```
+
+mod climate;
+mod direction;
+mod fairness;
 mod generator;
+mod generator_heightmap;
+mod generator_island;
+mod generator_perlin_simple;
+mod map_script;
+#[cfg(feature = "wasm-plugins")]
+mod map_script_wasm;
+mod specials;
 mod visualizer;
 mod world;
 
-pub use generator::{generate, LandDistribution, Parameters};
+pub use climate::Temperature;
+pub use direction::Direction;
+pub use fairness::{FairnessReport, ResourceAccess};
+pub use generator::{generate, LandDistribution, Parameters, ParametersError};
+pub use map_script::{
+    parse_option, FractalScript, HeightmapScript, IslandScript, MapScript, MapScriptError,
+    MapScripts, OptionKind, OptionSpec, PerlinSimpleScript, ScriptOptions,
+};
+#[cfg(feature = "wasm-plugins")]
+pub use map_script_wasm::{PluginError, WasmScript};
+pub use specials::SpecialWeights;
+pub use visualizer::{RenderError, Renderer};
 pub use world::World;
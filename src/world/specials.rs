@@ -0,0 +1,124 @@
+use super::{Parameters, World};
+use crate::tiles::Special;
+
+/// Number of variants of [`Special`], including [`Special::None`].
+const SPECIAL_COUNT: usize = Special::Furs as usize + 1;
+
+/// Relative weights of the specials a terrain allows, used when the map
+/// generator places specials.
+///
+/// When a tile gets a special, it is chosen among the specials its terrain
+/// allows with a probability proportional to their weights. For example,
+/// giving Fish a weight of 3 and Whales a weight of 1 makes three out of four
+/// ocean specials Fish. Specials with a weight of 0 are never placed. By
+/// default, all specials are equally likely, like in FreeCiv's classic
+/// ruleset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpecialWeights {
+    weights: [u32; SPECIAL_COUNT],
+}
+
+impl Default for SpecialWeights {
+    fn default() -> Self {
+        Self {
+            weights: [1; SPECIAL_COUNT],
+        }
+    }
+}
+
+impl SpecialWeights {
+    #[must_use]
+    pub const fn get(&self, special: Special) -> u32 {
+        self.weights[special as usize]
+    }
+
+    pub const fn set(&mut self, special: Special, weight: u32) {
+        self.weights[special as usize] = weight;
+    }
+}
+
+/// Places specials on the world's tiles.
+///
+/// Every tile whose terrain allows a special gets one with a probability of
+/// [`Parameters::special_frequency`] per mille, independently of the other
+/// tiles. The random numbers are derived from the seed with a generator that
+/// is the same on every platform, so a seed always yields the same specials.
+pub(crate) fn place_specials(world: &mut World, params: &Parameters) {
+    let rng = fastrand::Rng::with_seed(u64::from(params.seed));
+    let weights = &params.special_weights;
+
+    for tile in world.grid.iter_mut().flatten() {
+        let allowed = tile.terrain.allowed_specials();
+        let total: u32 = allowed.iter().map(|&special| weights.get(special)).sum();
+        if total == 0 || rng.u32(..1000) >= params.special_frequency {
+            continue;
+        }
+
+        let mut pick = rng.u32(..total);
+        for &special in allowed {
+            let weight = weights.get(special);
+            if pick < weight {
+                tile.special = special;
+                break;
+            }
+            pick -= weight;
+        }
+    }
+}
+
+#[test]
+fn test_special_frequencies() {
+    use crate::tiles::{Flags, Terrain, Tile};
+
+    let ocean = |params: &Parameters| {
+        let mut world = World {
+            width: params.width,
+            height: params.height,
+            grid: vec![
+                vec![Tile::new(Terrain::Ocean, Special::None, Flags::empty()); params.width];
+                params.height
+            ],
+            wrapping_x: false,
+            wrapping_y: false,
+        };
+        place_specials(&mut world, params);
+        world
+    };
+    let count = |world: &World, special: Special| {
+        world
+            .grid
+            .iter()
+            .flatten()
+            .filter(|tile| tile.special == special)
+            .count()
+    };
+
+    let mut params = Parameters {
+        width: 100,
+        height: 100,
+        special_frequency: 250,
+        ..Parameters::default()
+    };
+    params.special_weights.set(Special::Fish, 3);
+    params.special_weights.set(Special::Whales, 1);
+    let world = ocean(&params);
+
+    // 25% of the tiles get a special, three quarters of them Fish
+    let fish = count(&world, Special::Fish);
+    let whales = count(&world, Special::Whales);
+    assert!(
+        (2300..=2700).contains(&(fish + whales)),
+        "{fish} + {whales}"
+    );
+    assert!((1700..=2050).contains(&fish), "{fish}");
+    assert!((500..=750).contains(&whales), "{whales}");
+
+    // The same seed yields the same specials
+    assert_eq!(ocean(&params).fingerprint(), world.fingerprint());
+
+    params.special_weights.set(Special::Whales, 0);
+    assert_eq!(count(&ocean(&params), Special::Whales), 0);
+
+    params.special_frequency = 0;
+    assert_eq!(count(&ocean(&params), Special::None), 100 * 100);
+}
@@ -1 +1,415 @@
+use std::{fmt, io::Write};
 
+use image::{imageops, DynamicImage, Pixel, Rgba, RgbaImage};
+
+use super::{Direction, World};
+use crate::{
+    game::PlayerMap,
+    tiles::{
+        images::{sprite, Sprite, DARKNESS_SPRITES},
+        Terrain,
+    },
+};
+
+/// Micrometres per inch, for converting DPI into the pixels per metre that PNG
+/// files store.
+const MICROMETRES_PER_INCH: u64 = 25_400;
+
+/// The reason why a [`Renderer`] cannot render a world, as returned by
+/// [`Renderer::validate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderError {
+    /// [`Renderer::tile_size`] or [`Renderer::minimap_tile_size`] is 0.
+    ZeroTileSize,
+    /// The image would be wider or higher than `u32::MAX` pixels, or larger
+    /// than the biggest possible allocation.
+    TooLarge,
+}
+
+impl fmt::Display for RenderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ZeroTileSize => f.write_str("tiles must be at least one pixel large"),
+            Self::TooLarge => f.write_str("the image would be too large"),
+        }
+    }
+}
+
+impl std::error::Error for RenderError {}
+
+/// Renders worlds into images.
+///
+/// Sprites are drawn at their native size and scaled to `tile_size` pixels
+/// per tile. The tile sizes can be set freely, so check them with
+/// [`Renderer::validate`] before rendering.
+pub struct Renderer {
+    /// Width and height of a tile in the rendered image, in pixels.
+    pub tile_size: u32,
+    /// Resolution written into PNG files, in dots per inch, so that printed
+    /// maps come out at a sensible physical size. `None` leaves the physical
+    /// size unspecified.
+    pub dpi: Option<u32>,
+    /// Width and height of a tile on minimaps, in pixels.
+    pub minimap_tile_size: u32,
+}
+
+impl Default for Renderer {
+    fn default() -> Self {
+        Self {
+            tile_size: Self::sprite_size(),
+            dpi: None,
+            minimap_tile_size: 2,
+        }
+    }
+}
+
+impl Renderer {
+    /// Width and height of the tileset's sprites, in pixels.
+    #[must_use]
+    pub fn sprite_size() -> u32 {
+        sprite!("fog").width()
+    }
+
+    /// Checks that the tile sizes are not 0 and that images of the world fit
+    /// into `u32::MAX` pixels in each direction and into memory.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RenderError`] if the world cannot be rendered.
+    pub fn validate(&self, world: &World) -> Result<(), RenderError> {
+        image_size(world, self.tile_size)?;
+        image_size(world, self.minimap_tile_size)?;
+
+        Ok(())
+    }
+
+    /// Renders the whole world.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`Renderer::validate`] fails for the world.
+    #[must_use]
+    pub fn render(&self, world: &World) -> DynamicImage {
+        let mut image = self.blank_image(world);
+
+        for y in 0..world.height {
+            for x in 0..world.width {
+                let tile = render_tile(world, x, y);
+                self.place_tile(&mut image, &tile, x, y);
+            }
+        }
+
+        DynamicImage::ImageRgba8(image)
+    }
+
+    /// Renders the world as known to a player. Unknown tiles are black and
+    /// known tiles that are not currently visible are covered by fog. Like in
+    /// FreeCiv's clients, the edges of known tiles fade into darkness where
+    /// they border unknown tiles.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`Renderer::validate`] fails for the world.
+    #[must_use]
+    pub fn render_for_player(&self, world: &World, map: &PlayerMap) -> DynamicImage {
+        let mut image = self.blank_image(world);
+
+        for y in 0..world.height {
+            for x in 0..world.width {
+                if !map.is_known(x, y) {
+                    continue;
+                }
+
+                let mut tile = render_tile(world, x, y);
+                if !map.is_visible(x, y) {
+                    sprite!("fog").draw(&mut tile, 0, 0);
+                }
+                if let Some(darkness) = darkness(world, map, x, y) {
+                    darkness.draw(&mut tile, 0, 0);
+                }
+                self.place_tile(&mut image, &tile, x, y);
+            }
+        }
+
+        DynamicImage::ImageRgba8(image)
+    }
+
+    /// Renders an overview of the world with a single color per tile, like
+    /// the minimap of FreeCiv's clients. If a player map is given, unknown
+    /// tiles are black and tiles that are not currently visible are darker.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`Renderer::validate`] fails for the world.
+    #[must_use]
+    pub fn render_minimap(&self, world: &World, map: Option<&PlayerMap>) -> DynamicImage {
+        let size = self.minimap_tile_size;
+        let (width, height) = image_size(world, size).expect("invalid minimap tile size");
+
+        let image = RgbaImage::from_fn(width, height, |px_x, px_y| {
+            let (x, y) = ((px_x / size) as usize, (px_y / size) as usize);
+            let color = minimap_color(world.grid[y][x].terrain);
+
+            match map {
+                Some(map) if !map.is_known(x, y) => Rgba([0, 0, 0, 255]),
+                Some(map) if !map.is_visible(x, y) => color.map_without_alpha(|c| c / 2),
+                _ => color,
+            }
+        });
+
+        DynamicImage::ImageRgba8(image)
+    }
+
+    /// Encodes an image as PNG, including the physical size if a DPI is set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    pub fn write_png<W: Write>(
+        &self,
+        image: &DynamicImage,
+        writer: W,
+    ) -> Result<(), png::EncodingError> {
+        let image = image.to_rgba8();
+        let mut encoder = png::Encoder::new(writer, image.width(), image.height());
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header()?;
+        if let Some(dpi) = self.dpi {
+            // The pHYs chunk stores pixels per metre on each axis, followed by
+            // the unit, where 1 means metres.
+            let pixels_per_metre =
+                (u64::from(dpi) * 1_000_000 + MICROMETRES_PER_INCH / 2) / MICROMETRES_PER_INCH;
+            let pixels_per_metre = u32::try_from(pixels_per_metre).unwrap_or(u32::MAX);
+            let mut data = [0; 9];
+            data[0..4].copy_from_slice(&pixels_per_metre.to_be_bytes());
+            data[4..8].copy_from_slice(&pixels_per_metre.to_be_bytes());
+            data[8] = 1;
+            writer.write_chunk(png::chunk::pHYs, &data)?;
+        }
+        writer.write_image_data(&image)?;
+
+        writer.finish()
+    }
+
+    fn blank_image(&self, world: &World) -> RgbaImage {
+        let (width, height) = image_size(world, self.tile_size).expect("invalid tile size");
+
+        RgbaImage::from_pixel(width, height, Rgba([0, 0, 0, 255]))
+    }
+
+    /// Copies a rendered tile into the image, scaling it to the tile size.
+    fn place_tile(&self, image: &mut RgbaImage, tile: &RgbaImage, x: usize, y: usize) {
+        let px_x = i64::try_from(x).unwrap_or(i64::MAX) * i64::from(self.tile_size);
+        let px_y = i64::try_from(y).unwrap_or(i64::MAX) * i64::from(self.tile_size);
+
+        if tile.width() == self.tile_size {
+            imageops::replace(image, tile, px_x, px_y);
+        } else {
+            let scaled = imageops::resize(
+                tile,
+                self.tile_size,
+                self.tile_size,
+                imageops::FilterType::Triangle,
+            );
+            imageops::replace(image, &scaled, px_x, px_y);
+        }
+    }
+}
+
+/// The width and height in pixels of an image of the world with `tile_size`
+/// pixels per tile.
+fn image_size(world: &World, tile_size: u32) -> Result<(u32, u32), RenderError> {
+    if tile_size == 0 {
+        return Err(RenderError::ZeroTileSize);
+    }
+
+    let scale = |tiles: usize| {
+        u32::try_from(tiles)
+            .ok()
+            .and_then(|tiles| tiles.checked_mul(tile_size))
+            .ok_or(RenderError::TooLarge)
+    };
+
+    let (width, height) = (scale(world.width)?, scale(world.height)?);
+    // Allocations are limited to `isize::MAX` bytes.
+    let bytes = (u64::from(width) * u64::from(height))
+        .checked_mul(4)
+        .and_then(|bytes| isize::try_from(bytes).ok());
+    if bytes.is_none() {
+        return Err(RenderError::TooLarge);
+    }
+
+    Ok((width, height))
+}
+
+/// The color of a terrain on minimaps.
+const fn minimap_color(terrain: Terrain) -> Rgba<u8> {
+    match terrain {
+        Terrain::DeepOcean => Rgba([0, 0, 96, 255]),
+        Terrain::Ocean => Rgba([0, 0, 160, 255]),
+        Terrain::Lake => Rgba([64, 96, 224, 255]),
+        Terrain::Desert => Rgba([224, 208, 112, 255]),
+        Terrain::Forest => Rgba([0, 96, 0, 255]),
+        Terrain::Glacier => Rgba([240, 240, 240, 255]),
+        Terrain::Grassland => Rgba([64, 176, 32, 255]),
+        Terrain::Hills => Rgba([128, 128, 64, 255]),
+        Terrain::Jungle => Rgba([32, 128, 64, 255]),
+        Terrain::Mountains => Rgba([128, 96, 64, 255]),
+        Terrain::Plains => Rgba([160, 176, 64, 255]),
+        Terrain::Swamp => Rgba([96, 128, 112, 255]),
+        Terrain::Tundra => Rgba([160, 160, 144, 255]),
+    }
+}
+
+/// Renders a single tile at the tileset's native sprite size.
+fn render_tile(world: &World, x: usize, y: usize) -> RgbaImage {
+    // SAFETY: It is always within height and width.
+    let tile = unsafe { world.tile_at(x, y).unwrap_unchecked() };
+    let north = tile.north();
+    let east = tile.east();
+    let south = tile.south();
+    let west = tile.west();
+    let north_east = tile.north_east();
+    let south_east = tile.south_east();
+    let south_west = tile.south_west();
+    let north_west = tile.north_west();
+
+    let size = Renderer::sprite_size();
+    let mut image = RgbaImage::new(size, size);
+
+    tile.render(
+        &mut image,
+        north.as_deref(),
+        north_east.as_deref(),
+        east.as_deref(),
+        south_east.as_deref(),
+        south.as_deref(),
+        south_west.as_deref(),
+        west.as_deref(),
+        north_west.as_deref(),
+    );
+
+    image
+}
+
+/// The sprite that darkens the edges of a known tile towards its unknown
+/// neighbours to the north, east, south and west, if there are any. Tiles
+/// beyond the edges of the map do not count as unknown.
+fn darkness(world: &World, map: &PlayerMap, x: usize, y: usize) -> Option<&'static Sprite> {
+    let unknown = |direction| {
+        world
+            .step(x, y, direction)
+            .is_some_and(|(x, y)| !map.is_known(x, y))
+    };
+
+    let unknown = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ]
+    .map(unknown);
+
+    unknown
+        .contains(&true)
+        .then(|| DARKNESS_SPRITES.get(unknown))
+}
+
+#[test]
+fn test_tile_size_and_dpi() {
+    use super::{generate, Parameters};
+
+    let world = generate(Parameters {
+        width: 4,
+        height: 3,
+        ..Parameters::default()
+    })
+    .unwrap();
+    let renderer = Renderer {
+        tile_size: 8,
+        dpi: Some(254),
+        ..Renderer::default()
+    };
+    let image = renderer.render(&world);
+    assert_eq!((image.width(), image.height()), (32, 24));
+
+    let mut png = Vec::new();
+    renderer.write_png(&image, &mut png).unwrap();
+    let start = png.windows(4).position(|name| name == b"pHYs").unwrap() + 4;
+    // 254 DPI are exactly 10000 pixels per metre
+    assert_eq!(png[start..start + 4], 10000_u32.to_be_bytes());
+    assert_eq!(png[start + 4..start + 8], 10000_u32.to_be_bytes());
+    assert_eq!(png[start + 8], 1);
+
+    let decoded = image::load_from_memory(&png).unwrap();
+    assert_eq!(decoded.to_rgba8(), image.to_rgba8());
+
+    let minimap = renderer.render_minimap(&world, Some(&PlayerMap::unknown(4, 3)));
+    assert_eq!((minimap.width(), minimap.height()), (8, 6));
+    assert_eq!(minimap.to_rgba8().get_pixel(7, 5), &Rgba([0, 0, 0, 255]));
+
+    let invalid = |tile_size, minimap_tile_size| Renderer {
+        tile_size,
+        minimap_tile_size,
+        ..Renderer::default()
+    };
+    assert_eq!(renderer.validate(&world), Ok(()));
+    assert_eq!(
+        invalid(0, 2).validate(&world),
+        Err(RenderError::ZeroTileSize)
+    );
+    assert_eq!(
+        invalid(8, 0).validate(&world),
+        Err(RenderError::ZeroTileSize)
+    );
+    assert_eq!(
+        invalid(u32::MAX / 2, 2).validate(&world),
+        Err(RenderError::TooLarge)
+    );
+}
+
+#[test]
+fn test_darkness() {
+    use super::{generate, Parameters};
+
+    let world = generate(Parameters {
+        width: 4,
+        height: 3,
+        wrapping_x: false,
+        ..Parameters::default()
+    })
+    .unwrap();
+    let mut map = PlayerMap::unknown(4, 3);
+    for y in 0..3 {
+        map.see(0, y, 1);
+        map.see(1, y, 1);
+    }
+
+    assert!(darkness(&world, &map, 0, 1).is_none());
+    assert!(std::ptr::eq(
+        darkness(&world, &map, 1, 1).unwrap(),
+        sprite!("darkness_e")
+    ));
+    map.see(2, 0, 1);
+    assert!(std::ptr::eq(
+        darkness(&world, &map, 2, 0).unwrap(),
+        sprite!("darkness_es")
+    ));
+
+    // The darkness only covers the edges of the known tiles
+    let renderer = Renderer::default();
+    let size = Renderer::sprite_size();
+    let plain = renderer.render(&world).to_rgba8();
+    let known = renderer.render_for_player(&world, &map).to_rgba8();
+    assert_eq!(
+        known.get_pixel(size / 2, size + size / 2),
+        plain.get_pixel(size / 2, size + size / 2)
+    );
+    assert_ne!(
+        known.get_pixel(2 * size - 1, size + size / 2),
+        plain.get_pixel(2 * size - 1, size + size / 2)
+    );
+    assert_eq!(known.get_pixel(3 * size, size), &Rgba([0, 0, 0, 255]));
+}
@@ -1,9 +1,11 @@
 use std::ops::{Deref, DerefMut};
 
-use image::{imageops, DynamicImage};
+use image::DynamicImage;
 
-use crate::tiles::{Tile, TILE_IMAGE_SIZE};
+use super::{Direction, Renderer};
+use crate::{game::PlayerMap, tiles::Tile};
 
+#[derive(Clone)]
 pub struct World {
     pub(crate) width: usize,
     pub(crate) height: usize,
@@ -18,7 +20,35 @@ pub struct TileRef<'a> {
     world: &'a World,
 }
 
-impl TileRef<'_> {
+impl<'a> TileRef<'a> {
+    /// The coordinates of this tile.
+    #[must_use]
+    pub const fn position(&self) -> (usize, usize) {
+        (self.x, self.y)
+    }
+
+    /// Iterates over the up to eight tiles adjacent to this one.
+    pub fn neighbours(&self) -> impl Iterator<Item = TileRef<'a>> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(|direction| self.world.step(self.x, self.y, direction))
+            .filter_map(|(x, y)| self.world.tile_at(x, y))
+    }
+
+    /// The number of adjacent tiles that are water, including diagonals.
+    #[must_use]
+    pub fn adjacent_water_count(&self) -> usize {
+        self.neighbours()
+            .filter(|tile| tile.terrain.is_water())
+            .count()
+    }
+
+    /// Whether this is a land tile next to water, including diagonals.
+    #[must_use]
+    pub fn is_coastal(&self) -> bool {
+        !self.terrain.is_water() && self.neighbours().any(|tile| tile.terrain.is_water())
+    }
+
     pub fn north(&self) -> Option<Self> {
         let y = if self.y == 0 && self.world.wrapping_y {
             self.world.height - 1
@@ -131,6 +161,101 @@ impl<'a> DerefMut for TileRefMut<'a> {
 }
 
 impl World {
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The number of steps between two tiles, ignoring terrain. Diagonal steps
+    /// count as one, and the distance wraps around on wrapping maps.
+    #[must_use]
+    pub const fn distance(&self, a: (usize, usize), b: (usize, usize)) -> usize {
+        let dx = Self::axis_distance(a.0, b.0, self.width, self.wrapping_x);
+        let dy = Self::axis_distance(a.1, b.1, self.height, self.wrapping_y);
+
+        if dx > dy {
+            dx
+        } else {
+            dy
+        }
+    }
+
+    const fn axis_distance(a: usize, b: usize, length: usize, wrapping: bool) -> usize {
+        let distance = a.abs_diff(b);
+
+        if wrapping && length - distance < distance {
+            length - distance
+        } else {
+            distance
+        }
+    }
+
+    /// Returns the coordinates of the tile `dx`, `dy` steps away from the tile
+    /// at `x`, `y`, or `None` if that is beyond the edge of a non-wrapping map.
+    #[must_use]
+    pub fn offset(&self, x: usize, y: usize, dx: isize, dy: isize) -> Option<(usize, usize)> {
+        let x = Self::axis_offset(x, dx, self.width, self.wrapping_x)?;
+        let y = Self::axis_offset(y, dy, self.height, self.wrapping_y)?;
+
+        Some((x, y))
+    }
+
+    fn axis_offset(
+        coordinate: usize,
+        delta: isize,
+        length: usize,
+        wrapping: bool,
+    ) -> Option<usize> {
+        let length = isize::try_from(length).ok()?;
+        let moved = isize::try_from(coordinate).ok()? + delta;
+
+        if wrapping {
+            usize::try_from(moved.rem_euclid(length)).ok()
+        } else if (0..length).contains(&moved) {
+            usize::try_from(moved).ok()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the coordinates of the neighbouring tile in a direction, or
+    /// `None` if that is beyond the edge of a non-wrapping map.
+    #[must_use]
+    pub fn step(&self, x: usize, y: usize, direction: Direction) -> Option<(usize, usize)> {
+        let (dx, dy) = direction.delta();
+        self.offset(x, y, dx, dy)
+    }
+
+    /// Iterates over all tiles at most `radius` steps away from the tile at
+    /// `x`, `y`, including the tile itself. Tiles beyond the edges of
+    /// non-wrapping maps are skipped.
+    pub fn tiles_within(
+        &self,
+        x: usize,
+        y: usize,
+        radius: usize,
+    ) -> impl Iterator<Item = TileRef<'_>> {
+        let radius = isize::try_from(radius).unwrap_or(isize::MAX);
+
+        (-radius..=radius)
+            .flat_map(move |dy| (-radius..=radius).map(move |dx| (dx, dy)))
+            .filter_map(move |(dx, dy)| self.offset(x, y, dx, dy))
+            .filter_map(|(x, y)| self.tile_at(x, y))
+    }
+
+    /// Iterates over all land tiles next to water, row by row.
+    pub fn coastline_tiles(&self) -> impl Iterator<Item = TileRef<'_>> {
+        (0..self.height)
+            .flat_map(move |y| (0..self.width).map(move |x| (x, y)))
+            .filter_map(|(x, y)| self.tile_at(x, y))
+            .filter(TileRef::is_coastal)
+    }
+
     #[must_use]
     pub fn tile_at<'a>(&'a self, x: usize, y: usize) -> Option<TileRef<'a>> {
         let x = if self.wrapping_x && x >= self.width {
@@ -172,45 +297,139 @@ impl World {
         Some(TileRefMut { x, y, world: self })
     }
 
+    /// A short hash of the map's size, wrapping, terrain, specials and flags.
+    ///
+    /// The fingerprint only depends on the contents of the map, not on how it
+    /// is stored or serialized, so it is the same across runs and platforms.
+    /// Terrain and special resources are hashed by their identifiers in
+    /// savegames, not by the order of their variants. Terrain work in progress
+    /// and tile tags are ignored.
+    #[must_use]
+    pub fn fingerprint(&self) -> u64 {
+        // 64-bit FNV-1a, see http://www.isthe.com/chongo/tech/comp/fnv/
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        let header = [self.width as u64, self.height as u64]
+            .into_iter()
+            .flat_map(u64::to_le_bytes)
+            .chain([u8::from(self.wrapping_x), u8::from(self.wrapping_y)]);
+        let tiles = self.grid.iter().flatten().flat_map(|tile| {
+            let [low, high] = tile.flags.bits().to_le_bytes();
+            // The identifiers are ASCII characters.
+            [
+                tile.terrain.identifier() as u8,
+                tile.special.identifier() as u8,
+                low,
+                high,
+            ]
+        });
+
+        header.chain(tiles).fold(OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+        })
+    }
+
+    /// Renders the whole world with the default [`Renderer`].
+    #[must_use]
     pub fn render(&self) -> DynamicImage {
-        let mut image = DynamicImage::new_rgba8(
-            TILE_IMAGE_SIZE * self.width as u32,
-            TILE_IMAGE_SIZE * self.height as u32,
-        );
-
-        for y in 0..self.height {
-            for x in 0..self.width {
-                // SAFETY: It is always within height and width.
-                let tile = unsafe { self.tile_at(x, y).unwrap_unchecked() };
-                let north = tile.north();
-                let east = tile.east();
-                let south = tile.south();
-                let west = tile.west();
-                let north_east = tile.north_west();
-                let south_east = tile.south_east();
-                let south_west = tile.south_west();
-                let north_west = tile.north_west();
-
-                let px_x = x as u32 * TILE_IMAGE_SIZE;
-                let px_y = y as u32 * TILE_IMAGE_SIZE;
-
-                let mut tile_section =
-                    imageops::crop(&mut image, px_x, px_y, TILE_IMAGE_SIZE, TILE_IMAGE_SIZE);
-
-                tile.render(
-                    tile_section.deref_mut(),
-                    north.as_deref(),
-                    north_east.as_deref(),
-                    east.as_deref(),
-                    south_east.as_deref(),
-                    south.as_deref(),
-                    south_west.as_deref(),
-                    west.as_deref(),
-                    north_west.as_deref(),
-                );
-            }
-        }
+        Renderer::default().render(self)
+    }
 
-        image
+    /// Renders the world as known to a player with the default [`Renderer`].
+    #[must_use]
+    pub fn render_for_player(&self, map: &PlayerMap) -> DynamicImage {
+        Renderer::default().render_for_player(self, map)
     }
 }
+
+#[test]
+fn test_fingerprint() {
+    use super::{generate, Parameters};
+    use crate::tiles::{Flags, Special, Terrain};
+
+    let world = World {
+        width: 2,
+        height: 1,
+        grid: vec![vec![
+            Tile::new(Terrain::Ocean, Special::Fish, Flags::empty()),
+            Tile::new(Terrain::Grassland, Special::None, Flags::HAS_RIVER),
+        ]],
+        wrapping_x: true,
+        wrapping_y: false,
+    };
+    assert_eq!(world.fingerprint(), 0xe62f_6954_c774_422d);
+
+    let params = || Parameters {
+        width: 32,
+        height: 32,
+        ..Parameters::default()
+    };
+    let mut world = generate(params()).unwrap();
+    assert_eq!(
+        world.fingerprint(),
+        generate(params()).unwrap().fingerprint()
+    );
+
+    let other = generate(Parameters {
+        seed: 1,
+        ..params()
+    })
+    .unwrap();
+    assert_ne!(world.fingerprint(), other.fingerprint());
+
+    let fingerprint = world.fingerprint();
+    world
+        .tile_at_mut(3, 3)
+        .unwrap()
+        .flags
+        .toggle(Flags::HAS_HUT);
+    assert_ne!(world.fingerprint(), fingerprint);
+}
+
+#[test]
+fn test_coastal_helpers() {
+    use crate::tiles::{Flags, Special, Terrain};
+
+    // A 4x3 map with a single column of ocean on the left, not wrapping.
+    let grid = (0..3)
+        .map(|_| {
+            (0..4)
+                .map(|x| {
+                    let terrain = if x == 0 {
+                        Terrain::Ocean
+                    } else {
+                        Terrain::Grassland
+                    };
+                    Tile::new(terrain, Special::None, Flags::empty())
+                })
+                .collect()
+        })
+        .collect();
+    let mut world = World {
+        width: 4,
+        height: 3,
+        grid,
+        wrapping_x: false,
+        wrapping_y: false,
+    };
+
+    let tile = world.tile_at(1, 1).unwrap();
+    assert!(tile.is_coastal());
+    assert_eq!(tile.adjacent_water_count(), 3);
+    assert_eq!(world.tile_at(1, 0).unwrap().adjacent_water_count(), 2);
+    assert!(!world.tile_at(0, 1).unwrap().is_coastal());
+    assert!(!world.tile_at(3, 1).unwrap().is_coastal());
+    assert_eq!(
+        world
+            .coastline_tiles()
+            .map(|tile| tile.position())
+            .collect::<Vec<_>>(),
+        [(1, 0), (1, 1), (1, 2)]
+    );
+
+    // Wrapping brings the ocean next to the rightmost column.
+    world.wrapping_x = true;
+    assert!(world.tile_at(3, 1).unwrap().is_coastal());
+    assert_eq!(world.coastline_tiles().count(), 6);
+}
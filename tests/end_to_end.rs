@@ -0,0 +1,200 @@
+//! Smoke test of the whole crate: generate a world, let two AI players play a
+//! game on it, save, load and render it. The hash of the whole game state must
+//! survive the round trip through the savegame.
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use freeciv_rs::{game::ChecklistItem, prelude::*, units::UnitFlags};
+
+const TURNS: u32 = 50;
+/// Actions each unit may take per turn, so units that keep finding something
+/// to do do not loop.
+const ACTIONS_PER_UNIT: usize = 4;
+
+fn world() -> World {
+    generate(Parameters {
+        width: 40,
+        height: 25,
+        seed: 7,
+        ..Parameters::default()
+    })
+    .unwrap()
+}
+
+/// Picks the action a simple AI takes with a unit: attack whenever possible,
+/// found cities with settlers, build roads with workers, fortify defenders and
+/// move everything else around randomly.
+fn choose_action(unit: &Unit, actions: &[Action], rng: &fastrand::Rng) -> Option<Action> {
+    let flags = unit.kind().flags();
+    let wanted = if flags.contains(UnitFlags::CITIES) {
+        Some(Action::FoundCity)
+    } else if flags.contains(UnitFlags::SETTLERS) {
+        Some(Action::BuildRoad)
+    } else if unit.kind().defense() > unit.kind().attack() {
+        Some(Action::Fortify)
+    } else {
+        None
+    };
+
+    let attack = actions
+        .iter()
+        .find(|action| matches!(action, Action::Attack { .. }));
+    if let Some(&attack) = attack {
+        return Some(attack);
+    }
+    if let Some(wanted) = wanted.filter(|wanted| actions.contains(wanted)) {
+        return Some(wanted);
+    }
+
+    let moves: Vec<Action> = actions
+        .iter()
+        .copied()
+        .filter(|action| matches!(action, Action::Move { .. }))
+        .collect();
+    (!moves.is_empty()).then(|| moves[rng.usize(..moves.len())])
+}
+
+/// Lets a player research the cheapest technology it can once it is not
+/// researching anything.
+fn choose_research(game: &mut Game, player: PlayerId) {
+    if !game
+        .turn_checklist(player)
+        .contains(&ChecklistItem::ResearchNotSet)
+    {
+        return;
+    }
+
+    let technologies = game.player(player).unwrap().technologies();
+    let cheapest = freeciv_rs::research::ALL_TECHNOLOGIES
+        .iter()
+        .copied()
+        .filter(|&technology| technologies.can_research(technology))
+        .min_by_key(|technology| technology.total_bulbs());
+    if let Some(technology) = cheapest {
+        game.set_research(player, technology).unwrap();
+    }
+}
+
+/// Plays a game between two AI players.
+fn play(seed: u64) -> Game {
+    let mut game = Game::new(world(), &GameSetup::default()).unwrap();
+    let rng = fastrand::Rng::with_seed(seed);
+    let players: Vec<PlayerId> = game.players().iter().map(Player::id).collect();
+
+    for _ in 0..TURNS {
+        for &player in &players {
+            choose_research(&mut game, player);
+
+            let units: Vec<UnitId> = game
+                .units()
+                .filter(|unit| unit.owner() == player)
+                .map(Unit::id)
+                .collect();
+            for id in units {
+                for _ in 0..ACTIONS_PER_UNIT {
+                    // Units can be killed by counterattacks.
+                    let Some(unit) = game.unit(id) else {
+                        break;
+                    };
+                    let actions = game.available_actions(id);
+                    let Some(action) = choose_action(unit, &actions, &rng) else {
+                        break;
+                    };
+                    game.perform_action(id, action).unwrap();
+                }
+            }
+        }
+        game.end_turn();
+    }
+
+    game
+}
+
+/// Hashes what each player knows about the world.
+fn hash_player_map(map: &PlayerMap, world: &World, hasher: &mut DefaultHasher) {
+    for y in 0..world.height() {
+        for x in 0..world.width() {
+            (
+                map.is_known(x, y),
+                map.is_visible(x, y),
+                map.last_seen(x, y),
+            )
+                .hash(hasher);
+        }
+    }
+}
+
+/// Hashes the whole state of a game: the turn, the world, the research and
+/// maps of the players, and all units and cities.
+fn game_hash(game: &Game) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    game.turn().hash(&mut hasher);
+    game.world().fingerprint().hash(&mut hasher);
+    for player in game.players() {
+        player.id().hash(&mut hasher);
+        player.technologies().as_blocks().hash(&mut hasher);
+        player.researching().map(Technology::name).hash(&mut hasher);
+        let map = game.player_map(player.id()).unwrap();
+        hash_player_map(&map, game.world(), &mut hasher);
+    }
+    for unit in game.units() {
+        (unit.id(), unit.owner(), unit.kind().name(), unit.position()).hash(&mut hasher);
+        (unit.moves_left(), unit.hitpoints(), unit.activity() as u8).hash(&mut hasher);
+    }
+    for city in game.cities() {
+        (city.id(), city.owner(), city.position(), city.size()).hash(&mut hasher);
+    }
+
+    hasher.finish()
+}
+
+#[test]
+fn test_generate_simulate_save_load_render() {
+    let game = play(42);
+    assert_eq!(game.turn(), TURNS + 1);
+    assert_eq!(game.players().len(), 2);
+    for player in game.players() {
+        assert!(game.cities().any(|city| city.owner() == player.id()));
+        assert!(player.researching().is_some());
+    }
+    assert_eq!(
+        game_hash(&game),
+        game_hash(&play(42)),
+        "games with the same seed should be deterministic"
+    );
+
+    let savegame = Savegame::from_game(&game);
+    let path = std::env::temp_dir().join(format!("freeciv-rs-e2e-{}.sav", std::process::id()));
+    savegame.save(&path).unwrap();
+    let loaded = Savegame::load(&path);
+    std::fs::remove_file(&path).unwrap();
+    let loaded = loaded.unwrap();
+
+    assert!(!loaded.is_scenario());
+    let mut loaded = loaded.into_game().unwrap();
+    assert_eq!(game_hash(&loaded), game_hash(&game));
+
+    let renderer = Renderer {
+        tile_size: 8,
+        ..Renderer::default()
+    };
+    let image = renderer.render(loaded.world());
+    assert_eq!((image.width(), image.height()), (320, 200));
+    for player in loaded.players() {
+        let map = loaded.player_map(player.id()).unwrap();
+        let image = renderer.render_for_player(loaded.world(), &map);
+        assert_eq!((image.width(), image.height()), (320, 200));
+    }
+
+    let mut game = game;
+    game.end_turn();
+    loaded.end_turn();
+    assert_eq!(
+        game_hash(&loaded),
+        game_hash(&game),
+        "the loaded game should continue like the saved one"
+    );
+}
@@ -0,0 +1,658 @@
+//! Snapshot of the public API in `tests/public-api.txt`.
+//!
+//! The snapshot is generated from the rustdoc JSON of the crate and lists every
+//! public module, type, field, variant, function, constant and trait
+//! implementation with its signature, one per line. Adding, removing or
+//! changing an item changes the snapshot, so changes to the API cannot pass
+//! review unnoticed.
+//!
+//! Run the test with `UPDATE_PUBLIC_API=1` to write a new snapshot after an
+//! intended change.
+
+use std::{
+    collections::{BTreeSet, HashMap},
+    env, fs,
+    path::Path,
+    process::Command,
+};
+
+use serde_json::{Map, Value};
+
+/// Builds the rustdoc JSON of the crate and returns the parsed document.
+fn rustdoc_json() -> Value {
+    let target_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("public-api");
+    let status = Command::new(env!("CARGO"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["rustdoc", "--lib", "--quiet", "--target-dir"])
+        .arg(&target_dir)
+        .args(["--", "-Z", "unstable-options", "--output-format", "json"])
+        .status()
+        .expect("failed to run cargo rustdoc");
+    assert!(status.success(), "cargo rustdoc failed");
+
+    let json = fs::read_to_string(target_dir.join("doc/freeciv_rs.json")).unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
+/// Unstable marker traits that depend on the toolchain rather than the crate.
+const UNSTABLE_TRAITS: [&str; 3] = [
+    "core::marker::Freeze",
+    "core::marker::StructuralPartialEq",
+    "core::marker::UnsafeUnpin",
+];
+
+/// Lists the public items of a crate from its rustdoc JSON.
+///
+/// Items in private modules are listed under the public module that re-exports
+/// them, so moving them between private modules does not change the API.
+/// Re-exports of items that live in another public module, like those of the
+/// prelude, are listed as `pub use`.
+struct Api<'a> {
+    index: &'a Map<String, Value>,
+    paths: &'a Map<String, Value>,
+    /// The public path of every item of the crate, by id.
+    public: HashMap<String, String>,
+    lines: BTreeSet<String>,
+}
+
+impl<'a> Api<'a> {
+    fn item(&self, id: &Value) -> Option<&'a Value> {
+        self.index.get(&id.to_string())
+    }
+
+    /// The kind of an item and what is specific to that kind.
+    fn inner(item: &Value) -> (&str, &Value) {
+        match &item["inner"] {
+            Value::Object(inner) => inner
+                .iter()
+                .next()
+                .map(|(kind, inner)| (kind.as_str(), inner))
+                .unwrap(),
+            Value::String(kind) => (kind, &Value::Null),
+            _ => ("unknown", &Value::Null),
+        }
+    }
+
+    /// The path of an item where it is declared, which may be private.
+    fn declared_path(&self, id: &Value) -> Option<String> {
+        let segments = self.paths.get(&id.to_string())?["path"].as_array()?;
+        let segments: Vec<&str> = segments.iter().filter_map(Value::as_str).collect();
+
+        Some(segments.join("::"))
+    }
+
+    /// Whether a re-export in the module at `prefix` is the public home of the
+    /// item, because the item is declared in a private module below it.
+    /// Modules have no declared path, so private modules are always inlined.
+    fn is_home(&self, prefix: &str, id: &Value) -> bool {
+        match self.declared_path(id) {
+            Some(path) => path.starts_with(&format!("{prefix}::")),
+            None => self
+                .item(id)
+                .is_some_and(|item| item["visibility"] != "public"),
+        }
+    }
+
+    /// The items of a module, or `None` if the item is not a module.
+    fn module_items(&self, id: &Value) -> Option<&'a Vec<Value>> {
+        self.item(id)?["inner"].get("module")?["items"].as_array()
+    }
+
+    /// Records the public paths of the items in a module.
+    fn locate(&mut self, prefix: &str, id: &Value) {
+        let Some(item) = self.item(id) else {
+            return;
+        };
+        let (kind, inner) = Self::inner(item);
+        let (name, id) = match kind {
+            "use" if !self.is_home(prefix, &inner["id"]) => return,
+            "use" if inner["is_glob"] == true => {
+                for child in self.module_items(&inner["id"]).into_iter().flatten() {
+                    self.locate(prefix, child);
+                }
+                return;
+            }
+            "use" => (&inner["name"], &inner["id"]),
+            _ => (&item["name"], id),
+        };
+        let path = format!("{prefix}::{}", name.as_str().unwrap_or_default());
+        self.public.insert(id.to_string(), path.clone());
+
+        for child in self.module_items(id).into_iter().flatten() {
+            self.locate(&path, child);
+        }
+    }
+
+    fn visit(&mut self, prefix: &str, id: &Value) {
+        let Some(item) = self.item(id) else {
+            return;
+        };
+        let (kind, inner) = Self::inner(item);
+        if kind != "use" {
+            let name = item["name"].as_str().unwrap_or_default();
+            self.visit_item(&format!("{prefix}::{name}"), id);
+            return;
+        }
+
+        let name = inner["name"].as_str().unwrap();
+        match (self.is_home(prefix, &inner["id"]), inner["is_glob"] == true) {
+            (true, true) => {
+                for child in self.module_items(&inner["id"]).into_iter().flatten() {
+                    self.visit(prefix, child);
+                }
+            }
+            (true, false) => self.visit_item(&format!("{prefix}::{name}"), &inner["id"]),
+            (false, true) => {
+                let source = inner["source"].as_str().unwrap();
+                self.lines.insert(format!("pub use {prefix}::{source}::*"));
+            }
+            (false, false) => {
+                self.lines.insert(format!("pub use {prefix}::{name}"));
+            }
+        }
+    }
+
+    /// Lists an item under its public path.
+    fn visit_item(&mut self, path: &str, id: &Value) {
+        let Some(item) = self.item(id) else {
+            return;
+        };
+        let path = path.to_owned();
+        let (kind, inner) = Self::inner(item);
+
+        match kind {
+            "module" => {
+                self.lines.insert(format!("pub mod {path}"));
+                for child in inner["items"].as_array().unwrap() {
+                    self.visit(&path, child);
+                }
+            }
+            "struct" => {
+                let generics = self.generic_params(&inner["generics"]);
+                self.lines.insert(format!("pub struct {path}{generics}"));
+                let fields = match &inner["kind"] {
+                    Value::Object(kind) => kind
+                        .get("plain")
+                        .map(|plain| &plain["fields"])
+                        .or_else(|| kind.get("tuple")),
+                    _ => None,
+                };
+                for (index, field) in fields
+                    .and_then(Value::as_array)
+                    .into_iter()
+                    .flatten()
+                    .enumerate()
+                {
+                    self.field(&path, index, field);
+                }
+                self.impls(&path, &inner["impls"]);
+            }
+            "enum" => {
+                let generics = self.generic_params(&inner["generics"]);
+                self.lines.insert(format!("pub enum {path}{generics}"));
+                for variant in inner["variants"].as_array().unwrap() {
+                    self.variant(&path, variant);
+                }
+                self.impls(&path, &inner["impls"]);
+            }
+            "function" => {
+                let function = self.function(&path, inner);
+                self.lines.insert(function);
+            }
+            "constant" => {
+                let ty = self.ty(&inner["type"]);
+                self.lines.insert(format!("pub const {path}: {ty}"));
+            }
+            "static" => {
+                let ty = self.ty(&inner["type"]);
+                self.lines.insert(format!("pub static {path}: {ty}"));
+            }
+            "trait" => {
+                let generics = self.generic_params(&inner["generics"]);
+                self.lines.insert(format!("pub trait {path}{generics}"));
+                for member in inner["items"].as_array().unwrap() {
+                    self.member(&path, member);
+                }
+            }
+            "type_alias" => {
+                let generics = self.generic_params(&inner["generics"]);
+                let ty = self.ty(&inner["type"]);
+                self.lines
+                    .insert(format!("pub type {path}{generics} = {ty}"));
+            }
+            kind => {
+                self.lines.insert(format!("pub {kind} {path}"));
+            }
+        }
+    }
+
+    fn field(&mut self, path: &str, index: usize, id: &Value) {
+        let Some(field) = self.item(id) else {
+            return;
+        };
+        if field["visibility"] != "public" {
+            return;
+        }
+
+        let ty = self.ty(Self::inner(field).1);
+        let name = field["name"]
+            .as_str()
+            .map_or_else(|| index.to_string(), str::to_owned);
+        self.lines.insert(format!("pub {path}::{name}: {ty}"));
+    }
+
+    fn variant(&mut self, path: &str, id: &Value) {
+        let Some(variant) = self.item(id) else {
+            return;
+        };
+        let name = variant["name"].as_str().unwrap();
+        let path = format!("{path}::{name}");
+        let kind = &Self::inner(variant).1["kind"];
+
+        if let Some(fields) = kind.get("tuple").and_then(Value::as_array) {
+            let fields: Vec<String> = fields
+                .iter()
+                .filter_map(|field| self.item(field))
+                .map(|field| self.ty(Self::inner(field).1))
+                .collect();
+            self.lines.insert(format!("{path}({})", fields.join(", ")));
+        } else if let Some(fields) = kind
+            .get("struct")
+            .and_then(|kind| kind["fields"].as_array())
+        {
+            self.lines.insert(path.clone());
+            for field in fields {
+                let Some(field) = self.item(field) else {
+                    continue;
+                };
+                let name = field["name"].as_str().unwrap();
+                let ty = self.ty(Self::inner(field).1);
+                self.lines.insert(format!("{path}::{name}: {ty}"));
+            }
+        } else {
+            self.lines.insert(path);
+        }
+    }
+
+    /// Lists the inherent methods and associated constants of a type and the
+    /// traits it implements. Blanket implementations are left out.
+    fn impls(&mut self, path: &str, impls: &Value) {
+        for id in impls.as_array().unwrap() {
+            let Some(item) = self.item(id) else {
+                continue;
+            };
+            let implementation = Self::inner(item).1;
+            if !implementation["blanket_impl"].is_null() {
+                continue;
+            }
+
+            if implementation["trait"].is_null() {
+                for member in implementation["items"].as_array().unwrap() {
+                    if self
+                        .item(member)
+                        .is_some_and(|member| member["visibility"] == "public")
+                    {
+                        self.member(path, member);
+                    }
+                }
+            } else {
+                let generics = self.generic_params(&implementation["generics"]);
+                let negative = if implementation["is_negative"] == true {
+                    "!"
+                } else {
+                    ""
+                };
+                let name = self.path(&implementation["trait"]);
+                if UNSTABLE_TRAITS.contains(&name.as_str()) {
+                    continue;
+                }
+                let ty = self.ty(&implementation["for"]);
+                let bounds = self.where_clause(&implementation["generics"]);
+                self.lines
+                    .insert(format!("impl{generics} {negative}{name} for {ty}{bounds}"));
+            }
+        }
+    }
+
+    /// A method, associated constant or associated type of a type or trait.
+    fn member(&mut self, path: &str, id: &Value) {
+        let Some(item) = self.item(id) else {
+            return;
+        };
+        let name = item["name"].as_str().unwrap();
+        let (kind, inner) = Self::inner(item);
+
+        let line = match kind {
+            "function" => self.function(&format!("{path}::{name}"), inner),
+            "assoc_const" => format!("pub const {path}::{name}: {}", self.ty(&inner["type"])),
+            "assoc_type" => format!("pub type {path}::{name}"),
+            kind => format!("pub {kind} {path}::{name}"),
+        };
+        self.lines.insert(line);
+    }
+
+    fn function(&self, name: &str, function: &Value) -> String {
+        let header = &function["header"];
+        let mut qualifiers = String::from("pub ");
+        if header["is_const"] == true {
+            qualifiers.push_str("const ");
+        }
+        if header["is_unsafe"] == true {
+            qualifiers.push_str("unsafe ");
+        }
+
+        let sig = &function["sig"];
+        let inputs: Vec<String> = sig["inputs"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|input| {
+                let name = input[0].as_str().unwrap();
+                let ty = &input[1];
+                match (name, ty.get("borrowed_ref")) {
+                    ("self", _) if ty["generic"] == "Self" => String::from("self"),
+                    ("self", Some(reference)) if reference["type"]["generic"] == "Self" => {
+                        let mutability = if reference["is_mutable"] == true {
+                            "mut "
+                        } else {
+                            ""
+                        };
+                        format!("&{mutability}self")
+                    }
+                    (name, _) => format!("{name}: {}", self.ty(ty)),
+                }
+            })
+            .collect();
+        let output = if sig["output"].is_null() {
+            String::new()
+        } else {
+            format!(" -> {}", self.ty(&sig["output"]))
+        };
+
+        format!(
+            "{qualifiers}fn {name}{}({}){output}{}",
+            self.generic_params(&function["generics"]),
+            inputs.join(", "),
+            self.where_clause(&function["generics"]),
+        )
+    }
+
+    fn generic_params(&self, generics: &Value) -> String {
+        let params: Vec<String> = generics["params"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|param| {
+                let name = param["name"].as_str().unwrap();
+                let kind = &param["kind"];
+                if let Some(ty) = kind.get("type") {
+                    if ty["is_synthetic"] == true {
+                        return None;
+                    }
+                    let bounds = self.bounds(&ty["bounds"]);
+                    return Some(if bounds.is_empty() {
+                        name.to_owned()
+                    } else {
+                        format!("{name}: {bounds}")
+                    });
+                }
+                if let Some(constant) = kind.get("const") {
+                    return Some(format!("const {name}: {}", self.ty(&constant["type"])));
+                }
+
+                Some(name.to_owned())
+            })
+            .collect();
+
+        if params.is_empty() {
+            String::new()
+        } else {
+            format!("<{}>", params.join(", "))
+        }
+    }
+
+    fn where_clause(&self, generics: &Value) -> String {
+        let predicates: Vec<String> = generics["where_predicates"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|predicate| {
+                let bound = predicate.get("bound_predicate")?;
+                Some(format!(
+                    "{}: {}",
+                    self.ty(&bound["type"]),
+                    self.bounds(&bound["bounds"])
+                ))
+            })
+            .collect();
+
+        if predicates.is_empty() {
+            String::new()
+        } else {
+            format!(" where {}", predicates.join(", "))
+        }
+    }
+
+    fn bounds(&self, bounds: &Value) -> String {
+        let bounds: Vec<String> = bounds
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|bound| {
+                if let Some(bound) = bound.get("trait_bound") {
+                    let maybe = if bound["modifier"] == "maybe" {
+                        "?"
+                    } else {
+                        ""
+                    };
+                    return Some(format!("{maybe}{}", self.path(&bound["trait"])));
+                }
+
+                bound
+                    .get("outlives")
+                    .and_then(Value::as_str)
+                    .map(str::to_owned)
+            })
+            .collect();
+
+        bounds.join(" + ")
+    }
+
+    /// The full path of a type or trait with its generic arguments.
+    fn path(&self, path: &Value) -> String {
+        let id = &path["id"];
+        let name = self
+            .public
+            .get(&id.to_string())
+            .cloned()
+            .or_else(|| self.declared_path(id))
+            .unwrap_or_else(|| path["path"].as_str().unwrap_or_default().to_owned());
+
+        format!("{name}{}", self.generic_args(&path["args"]))
+    }
+
+    fn generic_args(&self, args: &Value) -> String {
+        if let Some(args) = args.get("angle_bracketed") {
+            let types = args["args"].as_array().into_iter().flatten().map(|arg| {
+                if let Some(ty) = arg.get("type") {
+                    self.ty(ty)
+                } else if let Some(lifetime) = arg.get("lifetime") {
+                    lifetime.as_str().unwrap().to_owned()
+                } else if let Some(constant) = arg.get("const") {
+                    constant["expr"].as_str().unwrap().to_owned()
+                } else {
+                    String::from("_")
+                }
+            });
+            let constraints =
+                args["constraints"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(|constraint| {
+                        let name = constraint["name"].as_str().unwrap();
+                        let binding = &constraint["binding"];
+                        if let Some(ty) = binding
+                            .get("equality")
+                            .and_then(|equality| equality.get("type"))
+                        {
+                            format!("{name} = {}", self.ty(ty))
+                        } else {
+                            format!("{name}: {}", self.bounds(&binding["constraint"]))
+                        }
+                    });
+            let args: Vec<String> = types.chain(constraints).collect();
+
+            return if args.is_empty() {
+                String::new()
+            } else {
+                format!("<{}>", args.join(", "))
+            };
+        }
+
+        if let Some(args) = args.get("parenthesized") {
+            let inputs: Vec<String> = args["inputs"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .map(|ty| self.ty(ty))
+                .collect();
+            let output = if args["output"].is_null() {
+                String::new()
+            } else {
+                format!(" -> {}", self.ty(&args["output"]))
+            };
+
+            return format!("({}){output}", inputs.join(", "));
+        }
+
+        String::new()
+    }
+
+    fn ty(&self, ty: &Value) -> String {
+        let Value::Object(ty) = ty else {
+            return String::from("_");
+        };
+        let Some((kind, ty)) = ty.iter().next() else {
+            return String::from("_");
+        };
+
+        match kind.as_str() {
+            "resolved_path" => self.path(ty),
+            "generic" | "primitive" => ty.as_str().unwrap().to_owned(),
+            "borrowed_ref" => {
+                let lifetime = ty["lifetime"]
+                    .as_str()
+                    .map(|lifetime| format!("{lifetime} "))
+                    .unwrap_or_default();
+                let mutability = if ty["is_mutable"] == true { "mut " } else { "" };
+                format!("&{lifetime}{mutability}{}", self.ty(&ty["type"]))
+            }
+            "raw_pointer" => {
+                let mutability = if ty["is_mutable"] == true {
+                    "mut"
+                } else {
+                    "const"
+                };
+                format!("*{mutability} {}", self.ty(&ty["type"]))
+            }
+            "tuple" => {
+                let types: Vec<String> = ty
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|ty| self.ty(ty))
+                    .collect();
+                format!("({})", types.join(", "))
+            }
+            "slice" => format!("[{}]", self.ty(ty)),
+            "array" => format!(
+                "[{}; {}]",
+                self.ty(&ty["type"]),
+                ty["len"].as_str().unwrap()
+            ),
+            "impl_trait" => format!("impl {}", self.bounds(ty)),
+            "dyn_trait" => {
+                let traits: Vec<String> = ty["traits"]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|bound| self.path(&bound["trait"]))
+                    .chain(ty["lifetime"].as_str().map(str::to_owned))
+                    .collect();
+                format!("dyn {}", traits.join(" + "))
+            }
+            "qualified_path" => {
+                let name = ty["name"].as_str().unwrap();
+                let self_type = self.ty(&ty["self_type"]);
+                if ty["trait"].is_null() {
+                    format!("{self_type}::{name}")
+                } else {
+                    format!("<{self_type} as {}>::{name}", self.path(&ty["trait"]))
+                }
+            }
+            "function_pointer" => {
+                let sig = &ty["sig"];
+                let inputs: Vec<String> = sig["inputs"]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|input| self.ty(&input[1]))
+                    .collect();
+                let output = if sig["output"].is_null() {
+                    String::new()
+                } else {
+                    format!(" -> {}", self.ty(&sig["output"]))
+                };
+                format!("fn({}){output}", inputs.join(", "))
+            }
+            _ => String::from("_"),
+        }
+    }
+}
+
+/// The public API of the crate, one sorted line per item.
+fn public_api() -> String {
+    let json = rustdoc_json();
+    let index = json["index"].as_object().unwrap();
+    let root = &index[&json["root"].to_string()];
+
+    let mut api = Api {
+        index,
+        paths: json["paths"].as_object().unwrap(),
+        public: HashMap::new(),
+        lines: BTreeSet::new(),
+    };
+    let name = root["name"].as_str().unwrap();
+    let items = Api::inner(root).1["items"].as_array().unwrap();
+    for child in items {
+        api.locate(name, child);
+    }
+    api.lines.insert(format!("pub mod {name}"));
+    for child in items {
+        api.visit(name, child);
+    }
+
+    api.lines.into_iter().map(|line| line + "\n").collect()
+}
+
+#[test]
+fn public_api_snapshot() {
+    let snapshot = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/public-api.txt");
+    let api = public_api();
+    if env::var_os("UPDATE_PUBLIC_API").is_some() {
+        fs::write(&snapshot, api).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&snapshot).unwrap_or_default();
+    let lines = |api: &str| api.lines().map(str::to_owned).collect::<BTreeSet<_>>();
+    let (now, before) = (lines(&api), lines(&expected));
+    let added: Vec<&String> = now.difference(&before).collect();
+    let removed: Vec<&String> = before.difference(&now).collect();
+    assert!(
+        added.is_empty() && removed.is_empty(),
+        "the public API changed, review the change and update the snapshot with \
+         UPDATE_PUBLIC_API=1\nadded: {added:#?}\nremoved: {removed:#?}"
+    );
+}